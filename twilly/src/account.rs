@@ -4,13 +4,16 @@ Contains Twilio account related functionality.
 
 */
 
-use std::fmt;
+use std::{fmt, future::Future};
 
+use futures::{stream, StreamExt};
 use reqwest::Method;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
 use strum_macros::{AsRefStr, Display, EnumIter, EnumString};
 
-use crate::{Client, TwilioError};
+use crate::{idempotency_headers, Client, ListPage, Page, TwilioError};
 
 /// Holds account related functions accessible
 /// on the client.
@@ -33,12 +36,32 @@ pub struct AccountPage {
     page: u16,
 }
 
+impl Page for AccountPage {
+    type Item = Account;
+
+    fn into_list_page(self) -> ListPage<Account> {
+        ListPage {
+            next_page_url: self
+                .next_page_uri
+                .map(|next_page_uri| format!("https://api.twilio.com{}", next_page_uri)),
+            items: self.accounts,
+        }
+    }
+}
+
 /// Details related to a specific account.
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+///
+/// The `auth_token` is a secret so is redacted from the `Debug` output and never
+/// serialized.
+#[derive(Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Account {
     pub status: Status,
     pub date_updated: String,
-    //pub auth_token: String,
+    /// The account's auth token, returned when a subaccount is created so it can be
+    /// used straight away, e.g. with `TwilioConfig::build`.
+    #[serde(skip_serializing)]
+    pub auth_token: String,
     pub friendly_name: String,
     pub owner_account_sid: String,
     pub uri: String,
@@ -48,12 +71,106 @@ pub struct Account {
     pub type_field: String,
 }
 
+impl fmt::Debug for Account {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Account")
+            .field("status", &self.status)
+            .field("date_updated", &self.date_updated)
+            .field("auth_token", &"[REDACTED]")
+            .field("friendly_name", &self.friendly_name)
+            .field("owner_account_sid", &self.owner_account_sid)
+            .field("uri", &self.uri)
+            .field("sid", &self.sid)
+            .field("date_created", &self.date_created)
+            .field("type_field", &self.type_field)
+            .finish()
+    }
+}
+
 impl fmt::Display for Account {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{} - {}", self.sid, self.status)
     }
 }
 
+/// The current balance of an account.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Balance {
+    pub account_sid: String,
+    /// Decimal amount, e.g. `"10.52"`. Negative when the account is in debt.
+    pub balance: String,
+    /// ISO 4217 currency code of the balance, e.g. `USD`.
+    pub currency: String,
+}
+
+/// Represents a page of usage records from the Twilio API.
+#[allow(dead_code)]
+#[derive(Deserialize)]
+pub struct UsageRecordPage {
+    usage_records: Vec<UsageRecord>,
+    next_page_uri: Option<String>,
+}
+
+impl Page for UsageRecordPage {
+    type Item = UsageRecord;
+
+    fn into_list_page(self) -> ListPage<UsageRecord> {
+        ListPage {
+            next_page_url: self
+                .next_page_uri
+                .map(|next_page_uri| format!("https://api.twilio.com{}", next_page_uri)),
+            items: self.usage_records,
+        }
+    }
+}
+
+/// Usage of a single category, e.g. `sms`, over a period.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UsageRecord {
+    pub account_sid: String,
+    pub category: String,
+    pub description: String,
+    /// First day of the period, `YYYY-MM-DD`.
+    pub start_date: String,
+    /// Last day of the period, `YYYY-MM-DD`.
+    pub end_date: String,
+    /// Number of usage events, e.g. messages sent.
+    #[serde(deserialize_with = "string_or_number")]
+    pub count: String,
+    pub count_unit: String,
+    /// Amount used, e.g. minutes of calls, in `usage_unit`s.
+    #[serde(deserialize_with = "string_or_number")]
+    pub usage: String,
+    pub usage_unit: String,
+    /// Total cost of the usage in `price_unit`.
+    #[serde(deserialize_with = "string_or_number")]
+    pub price: String,
+    pub price_unit: String,
+    pub uri: String,
+}
+
+/// Twilio returns some usage amounts as strings and others as numbers. Both are
+/// kept as strings to avoid losing precision.
+fn string_or_number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::String(value) => Ok(value),
+        Value::Null => Ok(String::new()),
+        value => Ok(value.to_string()),
+    }
+}
+
+/// Possible filters when listing usage records via the Twilio API
+#[skip_serializing_none]
+#[derive(Serialize)]
+#[serde(rename_all(serialize = "PascalCase"))]
+struct UsageRecordParams {
+    category: Option<String>,
+    start_date: Option<String>,
+    end_date: Option<String>,
+}
+
 /// Possible Account statuses.
 #[derive(
     AsRefStr,
@@ -99,11 +216,29 @@ pub struct ListOrUpdateParams {
     pub status: Option<Status>,
 }
 
-/// Possible options when creating an Account via the Twilio API
-#[derive(Serialize)]
+/// Possible options when creating an Account via the Twilio API, e.g.
+/// `CreateParams::default().friendly_name("Example")`.
+#[derive(Default, Serialize)]
 #[serde(rename_all(serialize = "PascalCase"))]
 pub struct CreateParams {
     pub friendly_name: Option<String>,
+    /// Sent as the `I-Twilio-Idempotency-Token` header, see `IDEMPOTENCY_TOKEN_HEADER`.
+    #[serde(skip)]
+    pub idempotency_token: Option<String>,
+}
+
+impl CreateParams {
+    /// Name of the sub-account, otherwise Twilio names it after the date it was created.
+    pub fn friendly_name(mut self, friendly_name: impl Into<String>) -> Self {
+        self.friendly_name = Some(friendly_name.into());
+        self
+    }
+
+    /// Token sent as the `I-Twilio-Idempotency-Token` header, see `IDEMPOTENCY_TOKEN_HEADER`.
+    pub fn idempotency_token(mut self, idempotency_token: impl Into<String>) -> Self {
+        self.idempotency_token = Some(idempotency_token.into());
+        self
+    }
 }
 
 impl<'a> Accounts<'a> {
@@ -113,10 +248,23 @@ impl<'a> Accounts<'a> {
     /// account SID.
     pub async fn get(&self, sid: Option<&str>) -> Result<Account, TwilioError> {
         self.client
-            .send_request::<Account, ()>(
+            .get_resource::<Account>(&format!(
+                "https://api.twilio.com/2010-04-01/Accounts/{}.json",
+                sid.unwrap_or_else(|| &self.client.config.account_sid)
+            ))
+            .await
+    }
+
+    /// [Gets an Account's balance](https://www.twilio.com/docs/usage/api/account-balance)
+    ///
+    /// Takes in an optional `sid` argument otherwise will default to the current config
+    /// account SID.
+    pub async fn balance(&self, sid: Option<&str>) -> Result<Balance, TwilioError> {
+        self.client
+            .send_request::<Balance, ()>(
                 Method::GET,
                 &format!(
-                    "https://api.twilio.com/2010-04-01/Accounts/{}.json",
+                    "https://api.twilio.com/2010-04-01/Accounts/{}/Balance.json",
                     sid.unwrap_or_else(|| &self.client.config.account_sid)
                 ),
                 None,
@@ -125,12 +273,22 @@ impl<'a> Accounts<'a> {
             .await
     }
 
+    /// Usage record functions for the account `sid`, defaulting to the current config
+    /// account SID.
+    pub fn usage_records<'b>(&self, sid: Option<&'b str>) -> UsageRecords<'a, 'b> {
+        UsageRecords {
+            client: self.client,
+            account_sid: sid,
+        }
+    }
+
     /// [Lists Accounts](https://www.twilio.com/docs/iam/api/account#read-multiple-account-resources)
     ///
     /// This will list all accounts that match the provided criteria.
     /// This scans all subaccounts and can also include the account making the request.
     ///
-    /// Accounts will be _eagerly_ paged until all retrieved.
+    /// Accounts will be _eagerly_ paged until all retrieved, following the `next_page_uri`
+    /// of each page so large account hierarchies aren't truncated.
     ///
     /// Takes optional parameters:
     /// - `friendly_name` - Return only accounts matching this friendly name
@@ -145,58 +303,52 @@ impl<'a> Accounts<'a> {
             status: status.cloned(),
         };
 
-        let mut accounts_page = self
-            .client
-            .send_request::<AccountPage, ListOrUpdateParams>(
-                Method::GET,
-                "https://api.twilio.com/2010-04-01/Accounts.json?PageSize=5",
-                Some(&params),
-                None,
+        self.client
+            .paginate::<AccountPage, ListOrUpdateParams>(
+                String::from("https://api.twilio.com/2010-04-01/Accounts.json?PageSize=5"),
+                Some(params),
             )
-            .await?;
-
-        let mut results: Vec<Account> = accounts_page.accounts;
-
-        while (accounts_page.next_page_uri).is_some() {
-            let full_url = format!(
-                "https://api.twilio.com{}",
-                accounts_page.next_page_uri.unwrap()
-            );
-            accounts_page = self
-                .client
-                .send_request::<AccountPage, ()>(Method::GET, &full_url, None, None)
-                .await?;
-
-            results.append(&mut accounts_page.accounts);
-        }
-
-        Ok(results)
+            .await
     }
 
     /// [Creates a sub-account](https://www.twilio.com/docs/iam/api/account#create-an-account-resource)
-    /// under the authenticated Twilio account. Takes in an optional
-    /// `friendly_name` argument otherwise defaults to _SubAccount Created at {YYYY-MM-DD HH:MM meridian}_.
+    /// under the authenticated Twilio account. Without a `friendly_name` the name defaults to
+    /// _SubAccount Created at {YYYY-MM-DD HH:MM meridian}_.
     ///
     /// Care should be taken when creating sub-accounts.
     /// - Sub-accounts cannot create other sub-accounts
     /// - Trial accounts can only have a single sub-account beneath it.
     ///
-    /// See documentation for detail.
-    pub async fn create(&self, friendly_name: Option<&str>) -> Result<Account, TwilioError> {
-        let params = CreateParams {
-            friendly_name: friendly_name.map(|friendly_name| friendly_name.to_string()),
-        };
-
+    /// The returned account includes its `auth_token` so requests can be made as the
+    /// sub-account straight away. See documentation for detail.
+    pub async fn create(&self, params: CreateParams) -> Result<Account, TwilioError> {
+        let headers = idempotency_headers(params.idempotency_token.as_deref())?;
         self.client
             .send_request::<Account, CreateParams>(
                 Method::POST,
                 "https://api.twilio.com/2010-04-01/Accounts.json",
                 Some(&params),
-                None,
+                headers,
             )
             .await
     }
 
+    /// Creates a sub-account named `friendly_name`, see `create`.
+    #[deprecated(
+        since = "0.2.2",
+        note = "use `create` with `CreateParams::default().friendly_name(..)`"
+    )]
+    pub async fn create_with_name(
+        &self,
+        friendly_name: Option<&str>,
+    ) -> Result<Account, TwilioError> {
+        self.create(CreateParams {
+            friendly_name: friendly_name.map(String::from),
+            ..Default::default()
+        })
+        .await
+    }
+
     /// [Updates an account resource](https://www.twilio.com/docs/iam/api/account#update-an-account-resource)
     /// under the authenticated Twilio account.
     ///
@@ -228,4 +380,208 @@ impl<'a> Accounts<'a> {
             )
             .await
     }
+
+    /// Runs the provided `operation` against every active subaccount of the authenticated
+    /// account. Each invocation receives a `Client` scoped to the subaccount via
+    /// `Client::for_subaccount`.
+    ///
+    /// - `concurrency` - the maximum number of subaccounts operated on at once.
+    ///
+    /// Returns the result of the operation keyed by subaccount SID. Results are in
+    /// order of completion rather than the order of the accounts list.
+    ///
+    /// Subaccount clients share the stats and circuit breaker of this client so consecutive
+    /// failures across subaccounts will stop further requests being sent.
+    pub async fn for_each_subaccount<T, F, Fut>(
+        &self,
+        concurrency: usize,
+        operation: F,
+    ) -> Result<Vec<(String, Result<T, TwilioError>)>, TwilioError>
+    where
+        F: Fn(Client) -> Fut,
+        Fut: Future<Output = Result<T, TwilioError>>,
+    {
+        let mut subaccounts = self.list(None, Some(&Status::Active)).await?;
+        subaccounts.retain(|account| account.sid != self.client.config.account_sid);
+
+        let results = stream::iter(subaccounts)
+            .map(|account| {
+                let operation_future = operation(self.client.for_subaccount(&account.sid));
+                async move { (account.sid, operation_future.await) }
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect::<Vec<(String, Result<T, TwilioError>)>>()
+            .await;
+
+        Ok(results)
+    }
+}
+
+/// Holds usage record functions for a single account.
+pub struct UsageRecords<'a, 'b> {
+    pub client: &'a Client,
+    /// SID of the account (`AC...`). The current config account SID is used when `None`.
+    pub account_sid: Option<&'b str>,
+}
+
+impl<'a, 'b> UsageRecords<'a, 'b> {
+    /// [Lists Usage Records](https://www.twilio.com/docs/usage/api/usage-record#read-multiple-usagerecord-resources)
+    ///
+    /// Records will be _eagerly_ paged until all retrieved.
+    ///
+    /// Takes optional parameters:
+    /// - `category` - Return only usage of this category, e.g. `sms` or `calls`
+    /// - `start_date` - Return only usage on or after this date
+    /// - `end_date` - Return only usage on or before this date
+    pub async fn list(
+        &self,
+        category: Option<&str>,
+        start_date: Option<chrono::NaiveDate>,
+        end_date: Option<chrono::NaiveDate>,
+    ) -> Result<Vec<UsageRecord>, TwilioError> {
+        let params = UsageRecordParams {
+            category: category.map(|category| category.to_string()),
+            start_date: start_date.map(|start_date| start_date.to_string()),
+            end_date: end_date.map(|end_date| end_date.to_string()),
+        };
+
+        self.client
+            .collect_pages(
+                self.client
+                    .list_pages::<UsageRecordPage, UsageRecordParams>(
+                        format!(
+                    "https://api.twilio.com/2010-04-01/Accounts/{}/Usage/Records.json?PageSize=50",
+                    self.account_sid
+                        .unwrap_or_else(|| &self.client.config.account_sid)
+                ),
+                        Some(params),
+                    ),
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{serve, test_config, MockResponse};
+    use std::sync::{Arc, Mutex};
+
+    use crate::{Client, Domain};
+
+    #[tokio::test]
+    async fn accounts_listed_across_pages() {
+        let server = serve([MockResponse::json(r#"{"first_page_uri":"/2010-04-01/Accounts.json?PageSize=1&Page=0","end":0,"previous_page_uri":null,"accounts":[{"sid":"AC1","friendly_name":"AC1","status":"active","type":"Full"}],"uri":"/2010-04-01/Accounts.json?PageSize=1&Page=0","page_size":1,"start":0,"next_page_uri":"/2010-04-01/Accounts.json?PageSize=1&Page=1&PageToken=PAAC1","page":0}"#), MockResponse::json(r#"{"first_page_uri":"/2010-04-01/Accounts.json?PageSize=1&Page=0","end":1,"previous_page_uri":"/2010-04-01/Accounts.json?PageSize=1&Page=0","accounts":[{"sid":"AC2","friendly_name":"AC2","status":"active","type":"Full"}],"uri":"/2010-04-01/Accounts.json?PageSize=1&Page=1&PageToken=PAAC1","page_size":1,"start":1,"next_page_uri":null,"page":1}"#)]).await;
+        let urls = Arc::new(Mutex::new(Vec::new()));
+        let observed_urls = urls.clone();
+        let client = Client::new(&test_config())
+            .with_base_url(Domain::Api, &server.url())
+            .with_response_observer(move |meta| {
+                observed_urls.lock().unwrap().push(meta.url.clone())
+            });
+
+        let accounts = client.accounts().list(None, None).await.unwrap();
+
+        assert_eq!(
+            accounts
+                .iter()
+                .map(|account| account.sid.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["AC1", "AC2"]
+        );
+        let urls = urls.lock().unwrap();
+        assert_eq!(urls.len(), 2);
+        assert!(urls[1].ends_with("/2010-04-01/Accounts.json?PageSize=1&Page=1&PageToken=PAAC1"));
+    }
+
+    #[tokio::test]
+    async fn subaccount_created_with_auth_token() {
+        let server = serve([MockResponse::json(r#"{"sid": "AC22222222222222222222222222222222", "friendly_name": "Example", "auth_token": "44444444444444444444444444444444", "owner_account_sid": "AC11111111111111111111111111111111", "status": "active", "type": "Full"}"#).status(201)]).await;
+
+        let subaccount = Client::new(&test_config())
+            .with_base_url(Domain::Api, &server.url())
+            .accounts()
+            .create(CreateParams::default().friendly_name("Example"))
+            .await
+            .unwrap();
+
+        let request = server.request();
+        assert!(request.starts_with("POST /2010-04-01/Accounts.json "));
+        assert!(request.ends_with("FriendlyName=Example"));
+        assert_eq!(subaccount.sid, "AC22222222222222222222222222222222");
+        assert_eq!(subaccount.auth_token, "44444444444444444444444444444444");
+        let debug = format!("{:?}", subaccount);
+        assert!(!debug.contains(&subaccount.auth_token));
+        assert!(debug.contains("[REDACTED]"));
+    }
+
+    #[tokio::test]
+    async fn account_status_sent_as_form_field() {
+        for (status, expected) in [
+            (Status::Active, "Status=active"),
+            (Status::Suspended, "Status=suspended"),
+            (Status::Closed, "Status=closed"),
+        ] {
+            let server = serve([MockResponse::json("{}")]).await;
+
+            Client::new(&test_config())
+                .with_base_url(Domain::Api, &server.url())
+                .accounts()
+                .update("AC00000000000000000000000000000000", None, Some(&status))
+                .await
+                .unwrap();
+
+            let request = server.request();
+            assert!(request
+                .starts_with("POST /2010-04-01/Accounts/AC00000000000000000000000000000000.json "));
+            assert!(request.ends_with(expected));
+        }
+    }
+
+    #[tokio::test]
+    async fn account_balance_deserialized() {
+        let server = serve([MockResponse::json(r#"{"currency": "USD", "balance": "10.52", "account_sid": "AC00000000000000000000000000000000"}"#)]).await;
+
+        let balance = Client::new(&test_config())
+            .with_base_url(Domain::Api, &server.url())
+            .accounts()
+            .balance(Some("AC00000000000000000000000000000000"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            balance,
+            Balance {
+                account_sid: String::from("AC00000000000000000000000000000000"),
+                balance: String::from("10.52"),
+                currency: String::from("USD"),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn usage_records_filtered_by_date_range() {
+        let server = serve([MockResponse::json(r#"{"usage_records": [{"category": "sms", "count": "12", "usage": 12, "price": 0.95, "start_date": "2024-01-01", "end_date": "2024-01-31"}], "next_page_uri": null}"#)]).await;
+
+        let records = Client::new(&test_config())
+            .with_base_url(Domain::Api, &server.url())
+            .accounts()
+            .usage_records(None)
+            .list(
+                Some("sms"),
+                chrono::NaiveDate::from_ymd_opt(2024, 1, 1),
+                chrono::NaiveDate::from_ymd_opt(2024, 1, 31),
+            )
+            .await
+            .unwrap();
+
+        let request = server.request();
+        assert!(request.starts_with(
+            "GET /2010-04-01/Accounts/AC11111111111111111111111111111111/Usage/Records.json?PageSize=50&Category=sms&StartDate=2024-01-01&EndDate=2024-01-31 "
+        ));
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].count, "12");
+        assert_eq!(records[0].usage, "12");
+        assert_eq!(records[0].price, "0.95");
+    }
 }
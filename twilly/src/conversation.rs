@@ -5,16 +5,36 @@ Contains Twilio conversation related functionality.
 */
 use std::fmt;
 
-use reqwest::Method;
-use serde::{Deserialize, Serialize};
+use futures::{stream, StreamExt};
+use reqwest::{Method, Url};
+use serde::{
+    de::DeserializeOwned,
+    ser::{SerializeMap, Serializer},
+    Deserialize, Serialize,
+};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
 use strum_macros::{AsRefStr, Display, EnumIter, EnumString};
 
-use crate::{participant_conversation::ParticipantConversations, Client, PageMeta, TwilioError};
+use crate::{
+    conversation_configuration::ConversationConfiguration,
+    conversation_message::ConversationMessages,
+    conversation_webhook::ConversationWebhooks,
+    idempotency_headers, page_size,
+    participant::{Participant, Participants},
+    participant_conversation::ParticipantConversations,
+    sid::ConversationSid,
+    util::{validate_iso8601_duration, validate_json_object},
+    Client, ListPage, Page, PageMeta, TwilioError,
+};
 
 /// Holds conversation related functions accessible
 /// on the client.
 pub struct Conversations<'a> {
     pub client: &'a Client,
+    /// SID of the Conversation Service (`IS...`) to target. The account's default
+    /// Conversation Service is used when `None`.
+    pub service_sid: Option<&'a str>,
 }
 
 /// Represents a page of conversations from the Twilio API.
@@ -25,39 +45,193 @@ pub struct ConversationPage {
     meta: PageMeta,
 }
 
+impl Page for ConversationPage {
+    type Item = Conversation;
+
+    fn into_list_page(self) -> ListPage<Conversation> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.conversations,
+        }
+    }
+}
+
 /// Details related to a specific conversation.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Conversation {
     pub sid: String,
     pub account_sid: String,
     pub chat_service_sid: String,
-    pub messaging_service_sid: String,
+    pub messaging_service_sid: Option<String>,
     pub unique_name: Option<String>,
     pub friendly_name: Option<String>,
     pub date_created: String,
     pub date_updated: String,
     pub state: State,
     pub url: String,
-    pub attributes: String,
-    pub timers: Timers,
+    pub attributes: Attributes,
+    pub timers: ConversationTimers,
     pub links: Links,
 }
 
+impl Conversation {
+    /// Decodes the Conversation's `attributes` JSON string.
+    pub fn parsed_attributes(&self) -> Result<Value, serde_json::Error> {
+        self.attributes.parse()
+    }
+
+    // URL of the Conversation's Participants, taken from `links` when present.
+    fn participants_url(&self) -> String {
+        link_or_else(&self.links.participants, || {
+            format!("{}/Participants", self.conversation_url())
+        })
+    }
+
+    // URL of the Conversation itself, built from the SIDs if Twilio didn't provide it.
+    fn conversation_url(&self) -> String {
+        link_or_else(&self.url, || {
+            format!(
+                "https://conversations.twilio.com/v1/Services/{}/Conversations/{}",
+                self.chat_service_sid, self.sid
+            )
+        })
+    }
+}
+
+// Prefers a URL from a resource's `links`, falling back to one built from SIDs when
+// Twilio didn't provide it.
+fn link_or_else(link: &str, fallback: impl FnOnce() -> String) -> String {
+    match link.is_empty() {
+        true => fallback(),
+        false => link.to_string(),
+    }
+}
+
 impl fmt::Display for Conversation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{} - {}", self.sid, self.state)
     }
 }
 
+/// A Conversation along with its participants, see `Conversations::list_with_participants`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConversationWithParticipants {
+    pub conversation: Conversation,
+    pub participants: Vec<Participant>,
+}
+
+/// Parameters for creating a Conversation. Every option can be chained from the default,
+/// e.g. `CreateConversation::default().unique_name("support")`.
+#[skip_serializing_none]
+#[derive(Default, Serialize)]
+#[serde(rename_all(serialize = "PascalCase"))]
+pub struct CreateConversation {
+    pub friendly_name: Option<String>,
+    pub unique_name: Option<String>,
+    /// Additional data, set from any serializable value via `attributes()`.
+    pub attributes: Option<Attributes>,
+    pub state: Option<State>,
+    /// ISO 8601 durations after which the Conversation becomes inactive or closed, e.g. `PT10M`.
+    #[serde(flatten, serialize_with = "serialize_timer_durations")]
+    pub timers: Option<ConversationTimers>,
+    /// Sent as the `I-Twilio-Idempotency-Token` header, see `IDEMPOTENCY_TOKEN_HEADER`.
+    #[serde(skip)]
+    pub idempotency_token: Option<String>,
+}
+
+impl CreateConversation {
+    pub fn friendly_name(mut self, friendly_name: impl Into<String>) -> Self {
+        self.friendly_name = Some(friendly_name.into());
+        self
+    }
+
+    pub fn unique_name(mut self, unique_name: impl Into<String>) -> Self {
+        self.unique_name = Some(unique_name.into());
+        self
+    }
+
+    /// Sets the attributes to `attributes` converted to a JSON string, as Twilio requires.
+    pub fn attributes<T: ?Sized + Serialize>(mut self, attributes: &T) -> Self {
+        self.attributes = Some(Attributes::new(attributes));
+        self
+    }
+
+    pub fn state(mut self, state: State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    pub fn timers(mut self, timers: ConversationTimers) -> Self {
+        self.timers = Some(timers);
+        self
+    }
+
+    pub fn idempotency_token(mut self, idempotency_token: impl Into<String>) -> Self {
+        self.idempotency_token = Some(idempotency_token.into());
+        self
+    }
+}
+
 /// Possible options when updating a Conversation
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all(serialize = "PascalCase"))]
 pub struct UpdateConversation {
     pub unique_name: Option<String>,
+    /// The Messaging Service (`MG...`) used to send messages in the Conversation.
+    pub messaging_service_sid: Option<String>,
     pub friendly_name: Option<String>,
     pub state: Option<State>,
-    pub attributes: Option<String>,
-    pub timers: Option<Timers>,
+    pub attributes: Option<Attributes>,
+    /// ISO 8601 durations after which the Conversation becomes inactive or closed, e.g. `PT10M`.
+    #[serde(flatten, serialize_with = "serialize_timer_durations")]
+    pub timers: Option<ConversationTimers>,
+}
+
+/// Additional data stored against a resource. Twilio holds attributes as a JSON
+/// string so any serializable value is converted on the way in, e.g.
+/// `Attributes::new(&json!({ "priority": "high" }))`, and can be decoded on the
+/// way out with `parse()`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Attributes(String);
+
+impl Attributes {
+    /// Converts `attributes` to a JSON string.
+    pub fn new<T: ?Sized + Serialize>(attributes: &T) -> Self {
+        Attributes(
+            serde_json::to_string(attributes)
+                .expect("Unable to convert provided attributes to a JSON string"),
+        )
+    }
+
+    /// Decodes the attributes as `T`.
+    pub fn parse<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(&self.0)
+    }
+
+    /// The attributes as the raw JSON string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Checks the attributes are a JSON object within Twilio's size limit, see
+    /// `util::validate_json_object`.
+    pub fn validate(&self) -> Result<(), TwilioError> {
+        validate_json_object("Attributes", &self.0)
+    }
+}
+
+impl From<String> for Attributes {
+    fn from(attributes: String) -> Self {
+        Attributes(attributes)
+    }
+}
+
+impl fmt::Display for Attributes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
 }
 
 /// The possible states of a conversation.
@@ -97,20 +271,72 @@ impl State {
     }
 }
 
-/// The timers configured for a Conversation's state.
+/// The timers of a Conversation's state. Set `inactive` and `closed` to ISO 8601 durations,
+/// e.g. `ConversationTimers::default().inactive("PT10M")`, when creating or updating a
+/// Conversation. Twilio returns the dates they elapse at in `date_inactive` and `date_closed`.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
-pub struct Timers {
+#[serde(default)]
+pub struct ConversationTimers {
     /// The time at which the Conversation will become inactive.
-    #[serde(rename(serialize = "Timers.Inactive"))]
     pub date_inactive: Option<String>,
     /// The time at which the Conversation will become closed.
-    #[serde(rename(serialize = "Timers.Closed"))]
     pub date_closed: Option<String>,
+    /// ISO 8601 duration of no activity after which the Conversation becomes inactive.
+    /// Must be at least 1 minute.
+    pub inactive: Option<String>,
+    /// ISO 8601 duration of no activity after which the Conversation is closed. Must be at
+    /// least 10 minutes.
+    pub closed: Option<String>,
+}
+
+/// Former name of `ConversationTimers`.
+#[deprecated(since = "0.2.2", note = "renamed to `ConversationTimers`")]
+pub type Timers = ConversationTimers;
+
+impl ConversationTimers {
+    pub fn inactive(mut self, inactive: impl Into<String>) -> Self {
+        self.inactive = Some(inactive.into());
+        self
+    }
+
+    pub fn closed(mut self, closed: impl Into<String>) -> Self {
+        self.closed = Some(closed.into());
+        self
+    }
+
+    // Checks the durations to send are in ISO 8601 format.
+    fn validate(&self) -> Result<(), TwilioError> {
+        for duration in [&self.inactive, &self.closed].into_iter().flatten() {
+            validate_iso8601_duration(duration)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Sends the durations of `timers` as the `Timers.Inactive` and `Timers.Closed` form fields.
+// The dates are only ever returned by Twilio so are left out.
+fn serialize_timer_durations<S: Serializer>(
+    timers: &Option<ConversationTimers>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(None)?;
+    if let Some(timers) = timers {
+        if let Some(inactive) = &timers.inactive {
+            map.serialize_entry("Timers.Inactive", inactive)?;
+        }
+        if let Some(closed) = &timers.closed {
+            map.serialize_entry("Timers.Closed", closed)?;
+        }
+    }
+
+    map.end()
 }
 
 /// Resources _linked_ to a conversation. These can be used to retrieve
 /// sub resources directly.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
 pub struct Links {
     pub participants: String,
     pub messages: String,
@@ -124,72 +350,211 @@ pub struct ListParams {
     pub start_date: Option<String>,
     pub end_date: Option<String>,
     pub state: Option<State>,
+    /// Applied to the results after fetching, not sent to Twilio.
+    #[serde(skip)]
+    pub order: Option<ListOrder>,
+    /// Number of Conversations fetched per request, between 1 and 1000. Defaults to 50.
+    #[serde(skip)]
+    pub page_size: Option<u16>,
+}
+
+/// Orderings that can be applied to listed Conversations.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ListOrder {
+    /// Oldest Conversation first.
+    DateCreated,
+    /// Ascending by Conversation SID.
+    Sid,
 }
 
 impl<'a> Conversations<'a> {
+    /// Scopes Conversation functions to a specific Conversation Service.
+    ///
+    /// Takes in the SID of the Conversation Service (`IS...`) to perform actions against.
+    pub fn service(&self, sid: &'a str) -> Conversations<'a> {
+        Conversations {
+            client: self.client,
+            service_sid: Some(sid),
+        }
+    }
+
+    /// [Creates a Conversation](https://www.twilio.com/docs/conversations/api/conversation-resource#create-a-conversation-resource)
+    ///
+    /// Creates a Conversation with the provided parameters, all of which are optional.
+    pub async fn create(&self, params: CreateConversation) -> Result<Conversation, TwilioError> {
+        if let Some(timers) = &params.timers {
+            timers.validate()?;
+        }
+        if let Some(attributes) = &params.attributes {
+            attributes.validate()?;
+        }
+
+        let headers = idempotency_headers(params.idempotency_token.as_deref())?;
+        self.client
+            .send_request::<Conversation, CreateConversation>(
+                Method::POST,
+                &self.conversations_url(),
+                Some(&params),
+                headers,
+            )
+            .await
+    }
+
     /// [Gets a Conversation](https://www.twilio.com/docs/conversations/api/conversation-resource#fetch-a-conversation-resource)
     ///
-    /// Takes in a `sid` argument which can also be the Conversations `uniqueName`.
+    /// Takes in a `sid` argument which can also be the Conversations `uniqueName`. It is
+    /// percent-encoded into the path so may contain any character, including spaces and `/`.
     pub async fn get(&self, sid: &str) -> Result<Conversation, TwilioError> {
         self.client
-            .send_request::<Conversation, ()>(
-                Method::GET,
-                &format!("https://conversations.twilio.com/v1/Conversations/{}", sid),
-                None,
-                None,
-            )
+            .get_resource::<Conversation>(&self.conversation_url(sid))
+            .await
+    }
+
+    /// [Gets a Conversation](https://www.twilio.com/docs/conversations/api/conversation-resource#fetch-a-conversation-resource)
+    /// by its unique name.
+    ///
+    /// Equivalent to `get`, which accepts unique names as well as SIDs.
+    pub async fn get_by_unique_name(&self, unique_name: &str) -> Result<Conversation, TwilioError> {
+        self.get(unique_name).await
+    }
+
+    /// Gets each of the Conversations in `sids`, up to `concurrency` at once. Useful for
+    /// checking the latest state of Conversations known to another system without
+    /// listing every Conversation.
+    ///
+    /// Returns the outcome of each get keyed by SID, in the order of `sids`. Conversations
+    /// that don't exist fail with an error for which `TwilioError::is_not_found` is `true`.
+    pub async fn get_many(
+        &self,
+        sids: &[ConversationSid],
+        concurrency: usize,
+    ) -> Vec<(ConversationSid, Result<Conversation, TwilioError>)> {
+        stream::iter(sids)
+            .map(|sid| async move { (sid.clone(), self.get(sid.as_str()).await) })
+            .buffered(concurrency.max(1))
+            .collect()
             .await
     }
 
     /// [Lists Conversations](https://www.twilio.com/docs/conversations/api/conversation-resource#read-multiple-conversation-resources)
     ///
-    /// This will eagerly fetch *all* conversations on the Twilio account and sort by recent message activity.
+    /// This will eagerly fetch *all* conversations on the Twilio account and sort by recent message activity
+    /// unless an `order` is provided.
     ///
     /// Takes optional parameters:
     /// - `start_date` - When the Conversation started, ISO8601 format e.g. `YYYY-MM-DDT00:00:00Z`.
     /// - `end_date` - When the Conversation ended, ISO8601 format e.g. `YYYY-MM-DDT00:00:00Z`.
-    /// - `state` - Filter by state.
+    /// - `state` - Filter by state. Sent as the `State` query parameter so filtering is applied
+    ///   by Twilio rather than after fetching.
+    /// - `order` - Sort the results once fetched, giving a stable order across runs.
+    ///
+    /// See `list_with_params` to also set the page size.
     pub async fn list(
         &self,
         start_date: Option<chrono::NaiveDate>,
         end_date: Option<chrono::NaiveDate>,
         state: Option<State>,
+        order: Option<ListOrder>,
     ) -> Result<Vec<Conversation>, TwilioError> {
-        let params = ListParams {
+        self.list_with_params(ListParams {
             start_date: start_date.map(|start_date| start_date.to_string()),
             end_date: end_date.map(|end_date| end_date.to_string()),
             state,
-        };
+            order,
+            page_size: None,
+        })
+        .await
+    }
 
-        let mut conversations_page = self
+    /// Lists Conversations as `list` does, taking the filters, ordering and page size as
+    /// `ListParams`. A `page_size` outside of 1-1000 is rejected before any request is sent.
+    pub async fn list_with_params(
+        &self,
+        params: ListParams,
+    ) -> Result<Vec<Conversation>, TwilioError> {
+        let url = format!(
+            "{}?PageSize={}",
+            self.conversations_url(),
+            page_size(params.page_size)?
+        );
+        let order = params.order.clone();
+        let mut results = self
             .client
-            .send_request::<ConversationPage, ListParams>(
-                Method::GET,
-                "https://conversations.twilio.com/v1/Conversations",
-                Some(&params),
-                None,
-            )
+            .paginate::<ConversationPage, ListParams>(url, Some(params))
             .await?;
 
-        let mut results: Vec<Conversation> = conversations_page.conversations;
-
-        while (conversations_page.meta.next_page_url).is_some() {
-            conversations_page = self
-                .client
-                .send_request::<ConversationPage, ()>(
-                    Method::GET,
-                    &conversations_page.meta.next_page_url.unwrap(),
-                    None,
-                    None,
-                )
-                .await?;
-
-            results.append(&mut conversations_page.conversations);
+        match order {
+            // ISO8601 timestamps sort correctly as strings. SIDs break ties for
+            // Conversations created in the same second.
+            Some(ListOrder::DateCreated) => results.sort_by(|a, b| {
+                a.date_created
+                    .cmp(&b.date_created)
+                    .then_with(|| a.sid.cmp(&b.sid))
+            }),
+            Some(ListOrder::Sid) => results.sort_by(|a, b| a.sid.cmp(&b.sid)),
+            None => (),
         }
 
         Ok(results)
     }
 
+    /// Lists Conversations last updated after `updated_after`, useful for incrementally
+    /// mirroring Conversations between runs.
+    ///
+    /// Twilio doesn't support filtering by update time so all Conversations are fetched
+    /// and filtered on `date_updated`. Conversations with a `date_updated` that can't be
+    /// parsed are included rather than risk missing changes, the same as the Sync
+    /// `list_by_date_created` filters.
+    pub async fn list_updated_after(
+        &self,
+        updated_after: chrono::DateTime<chrono::Utc>,
+        order: Option<ListOrder>,
+    ) -> Result<Vec<Conversation>, TwilioError> {
+        let mut conversations = self.list(None, None, None, order).await?;
+
+        conversations.retain(|conversation| {
+            chrono::DateTime::parse_from_rfc3339(&conversation.date_updated)
+                .map_or(true, |date_updated| date_updated > updated_after)
+        });
+
+        Ok(conversations)
+    }
+
+    /// Lists Conversations as `list` does, then fetches the participants of each, up to
+    /// `concurrency` Conversations at once. Avoids listing participants one Conversation
+    /// at a time when building a roster or report.
+    ///
+    /// Conversations are returned in the same order as `list`. Fails if the participants
+    /// of any Conversation can't be fetched.
+    pub async fn list_with_participants(
+        &self,
+        start_date: Option<chrono::NaiveDate>,
+        end_date: Option<chrono::NaiveDate>,
+        state: Option<State>,
+        order: Option<ListOrder>,
+        concurrency: usize,
+    ) -> Result<Vec<ConversationWithParticipants>, TwilioError> {
+        let conversations = self.list(start_date, end_date, state, order).await?;
+
+        stream::iter(conversations)
+            .map(|conversation| async move {
+                let participants = self
+                    .participants(&conversation.sid)
+                    .list_from(&conversation.participants_url())
+                    .await?;
+
+                Ok(ConversationWithParticipants {
+                    conversation,
+                    participants,
+                })
+            })
+            .buffered(concurrency.max(1))
+            .collect::<Vec<Result<ConversationWithParticipants, TwilioError>>>()
+            .await
+            .into_iter()
+            .collect()
+    }
+
     /// [Update a Conversation](https://www.twilio.com/docs/conversations/api/conversation-resource#update-conversation)
     ///
     /// Takes in a `sid` argument which can also be the conversations `uniqueName` and updates the resource with the
@@ -199,10 +564,17 @@ impl<'a> Conversations<'a> {
         sid: &str,
         updates: UpdateConversation,
     ) -> Result<Conversation, TwilioError> {
+        if let Some(timers) = &updates.timers {
+            timers.validate()?;
+        }
+        if let Some(attributes) = &updates.attributes {
+            attributes.validate()?;
+        }
+
         self.client
             .send_request::<Conversation, UpdateConversation>(
                 Method::POST,
-                &format!("https://conversations.twilio.com/v1/Conversations/{}", sid),
+                &self.conversation_url(sid),
                 Some(&updates),
                 None,
             )
@@ -216,17 +588,394 @@ impl<'a> Conversations<'a> {
         self.client
             .send_request_and_ignore_response::<()>(
                 Method::DELETE,
-                &format!("https://conversations.twilio.com/v1/Conversations/{}", sid),
+                &self.conversation_url(sid),
                 None,
                 None,
             )
             .await
     }
 
+    /// Deletes the Conversations with the provided `sids`, up to `concurrency` at once.
+    /// Every SID is attempted even if some fail. Rate limited deletes are retried with
+    /// backoff when retries are enabled via `Client::with_retry`.
+    ///
+    /// Returns the outcome of each delete keyed by SID, in order of completion.
+    pub async fn delete_many(
+        &self,
+        sids: &[ConversationSid],
+        concurrency: usize,
+    ) -> Vec<(ConversationSid, Result<(), TwilioError>)> {
+        stream::iter(sids)
+            .map(|sid| async move { (sid.clone(), self.delete(sid.as_str()).await) })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Closes the Conversations with the provided `sids`, up to `concurrency` at once.
+    /// Every SID is attempted even if some fail.
+    ///
+    /// Returns the outcome of each update keyed by SID, in order of completion.
+    pub async fn close_many(
+        &self,
+        sids: &[ConversationSid],
+        concurrency: usize,
+    ) -> Vec<(ConversationSid, Result<Conversation, TwilioError>)> {
+        stream::iter(sids)
+            .map(|sid| async move {
+                let result = self
+                    .update(
+                        sid.as_str(),
+                        UpdateConversation {
+                            unique_name: None,
+                            messaging_service_sid: None,
+                            friendly_name: None,
+                            state: Some(State::Closed),
+                            attributes: None,
+                            timers: None,
+                        },
+                    )
+                    .await;
+
+                (sid.clone(), result)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Participant related functions for the Conversation with the provided `conversation_sid`.
+    pub fn participants<'b>(&self, conversation_sid: &'b str) -> Participants<'a, 'b> {
+        Participants {
+            client: self.client,
+            service_sid: self.service_sid,
+            conversation_sid,
+        }
+    }
+
+    /// Message related functions for the Conversation with the provided `conversation_sid`.
+    pub fn messages<'b>(&self, conversation_sid: &'b str) -> ConversationMessages<'a, 'b> {
+        ConversationMessages {
+            client: self.client,
+            service_sid: self.service_sid,
+            conversation_sid,
+        }
+    }
+
+    /// Scoped webhook related functions for the Conversation with the provided `conversation_sid`.
+    pub fn webhooks<'b>(&self, conversation_sid: &'b str) -> ConversationWebhooks<'a, 'b> {
+        ConversationWebhooks {
+            client: self.client,
+            service_sid: self.service_sid,
+            conversation_sid,
+        }
+    }
+
+    /// Functions for the account-wide Conversations configuration.
+    pub fn configuration(&self) -> ConversationConfiguration<'a> {
+        ConversationConfiguration {
+            client: self.client,
+        }
+    }
+
     /// Participant Conversation related functions.
     pub fn participant_conversations(&self) -> ParticipantConversations {
         ParticipantConversations {
             client: self.client,
+            service_sid: self.service_sid,
         }
     }
+
+    // URL of the Conversation with the SID or unique name `identifier`, encoded as a
+    // single path segment.
+    fn conversation_url(&self, identifier: &str) -> String {
+        let mut url = Url::parse(&self.conversations_url()).expect("Invalid Conversations URL");
+        url.path_segments_mut()
+            .expect("Conversations URL cannot be a base")
+            .push(identifier);
+
+        url.into()
+    }
+
+    // Base URL of Conversations, scoped to the Conversation Service if one was provided.
+    pub(crate) fn conversations_url(&self) -> String {
+        match self.service_sid {
+            Some(service_sid) => format!(
+                "https://conversations.twilio.com/v1/Services/{}/Conversations",
+                service_sid
+            ),
+            None => String::from("https://conversations.twilio.com/v1/Conversations"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{serve, test_config, MockResponse};
+    use crate::{Client, Domain, ErrorKind};
+
+    const SID_1: &str = "CH00000000000000000000000000000001";
+    const SID_2: &str = "CH00000000000000000000000000000002";
+    const SID_3: &str = "CH00000000000000000000000000000003";
+
+    #[tokio::test]
+    async fn list_state_sent_as_query_parameter() {
+        let server = serve([MockResponse::json(
+            r#"{"conversations":[{"sid":"CH1","state":"active"}],"meta":{"page":0,"page_size":50,"first_page_url":"","next_page_url":null,"key":"conversations"}}"#,
+        )])
+        .await;
+
+        let conversations = server
+            .client(Domain::Conversations)
+            .conversations()
+            .list(None, None, Some(State::Active), Some(ListOrder::Sid))
+            .await
+            .unwrap();
+
+        assert_eq!(conversations.len(), 1);
+        assert!(server
+            .request()
+            .starts_with("GET /v1/Conversations?PageSize=50&State=active "));
+    }
+
+    #[test]
+    fn timer_durations_sent_as_form_fields() {
+        let updates = UpdateConversation {
+            unique_name: None,
+            messaging_service_sid: None,
+            friendly_name: None,
+            state: Some(State::Active),
+            attributes: None,
+            timers: Some(ConversationTimers {
+                // Returned by Twilio so never sent.
+                date_inactive: Some(String::from("2024-01-01T00:10:00Z")),
+                ..ConversationTimers::default()
+                    .inactive("PT10M")
+                    .closed("P1D")
+            }),
+        };
+
+        let request = reqwest::Client::new()
+            .post("https://conversations.twilio.com/v1/Conversations/CH1")
+            .form(&updates)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.body().unwrap().as_bytes(),
+            Some("State=active&Timers.Inactive=PT10M&Timers.Closed=P1D".as_bytes())
+        );
+    }
+
+    #[test]
+    fn timers_returned_by_twilio_parsed() {
+        let conversation: Conversation = serde_json::from_str(
+            r#"{"sid": "CH1", "timers": {"date_inactive": "2024-01-01T00:10:00Z", "date_closed": null}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            conversation.timers,
+            ConversationTimers {
+                date_inactive: Some(String::from("2024-01-01T00:10:00Z")),
+                ..ConversationTimers::default()
+            }
+        );
+        assert!(ConversationTimers::default()
+            .inactive("10 minutes")
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn attributes_round_trip_struct() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Ticket {
+            priority: String,
+            escalated: bool,
+        }
+
+        let ticket = Ticket {
+            priority: String::from("high"),
+            escalated: true,
+        };
+        let attributes = Attributes::new(&ticket);
+        assert_eq!(
+            attributes.as_str(),
+            r#"{"priority":"high","escalated":true}"#
+        );
+
+        // Received from Twilio as a plain JSON string field.
+        let conversation: Conversation = serde_json::from_str(
+            r#"{"sid": "CH1", "attributes": "{\"priority\":\"high\",\"escalated\":true}"}"#,
+        )
+        .unwrap();
+        assert_eq!(conversation.attributes, attributes);
+        assert_eq!(conversation.attributes.parse::<Ticket>().unwrap(), ticket);
+    }
+
+    #[test]
+    fn unique_name_encoded_into_path() {
+        let client = Client::new(&crate::TwilioConfig::default());
+        let conversations = client.conversations();
+
+        assert_eq!(
+            conversations.conversation_url("support chat"),
+            "https://conversations.twilio.com/v1/Conversations/support%20chat"
+        );
+        assert_eq!(
+            conversations.service("IS1").conversation_url("a/b"),
+            "https://conversations.twilio.com/v1/Services/IS1/Conversations/a%2Fb"
+        );
+    }
+
+    #[tokio::test]
+    async fn conversation_created_with_unique_name() {
+        let server = serve([MockResponse::json(
+            r#"{"sid":"CH1","unique_name":"support","state":"active"}"#,
+        )
+        .status(201)])
+        .await;
+        let client =
+            Client::new(&test_config()).with_base_url(Domain::Conversations, &server.url());
+
+        let conversation = client
+            .conversations()
+            .create(
+                CreateConversation::default()
+                    .unique_name("support")
+                    .attributes(&serde_json::json!({ "priority": 1 })),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(conversation.sid, "CH1");
+        let request = server.request();
+        assert!(request.starts_with("POST /v1/Conversations "));
+        assert!(request.ends_with("UniqueName=support&Attributes=%7B%22priority%22%3A1%7D"));
+    }
+
+    #[tokio::test]
+    async fn conversation_fetched_by_unique_name() {
+        let server = serve([MockResponse::json(
+            r#"{"sid":"CH1","unique_name":"support chat","state":"active"}"#,
+        )])
+        .await;
+        let client =
+            Client::new(&test_config()).with_base_url(Domain::Conversations, &server.url());
+
+        let conversation = client
+            .conversations()
+            .get_by_unique_name("support chat")
+            .await
+            .unwrap();
+
+        assert_eq!(conversation.sid, "CH1");
+        assert_eq!(conversation.unique_name.as_deref(), Some("support chat"));
+        let request = server.request();
+        assert!(request.starts_with("GET /v1/Conversations/support%20chat "));
+    }
+
+    #[tokio::test]
+    async fn scalar_attributes_rejected_before_sending() {
+        // Nothing listens on the base URL so the error can only come from validation.
+        let client =
+            Client::new(&test_config()).with_base_url(Domain::Conversations, "http://127.0.0.1:9");
+
+        let error = client
+            .conversations()
+            .create(CreateConversation::default().attributes("high priority"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.kind, ErrorKind::ValidationError(_)));
+    }
+
+    #[tokio::test]
+    async fn delete_many_attempts_every_sid() {
+        let server = serve([MockResponse::new(204), MockResponse::json(r#"{"code": 20404, "message": "The requested resource was not found", "more_info": "https://www.twilio.com/docs/errors/20404", "status": 404}"#).status(404), MockResponse::new(204)]).await;
+        let client =
+            Client::new(&test_config()).with_base_url(Domain::Conversations, &server.url());
+
+        let sids = [SID_1, SID_2, SID_3]
+            .map(|sid| ConversationSid::new(sid).unwrap())
+            .to_vec();
+        let results = client.conversations().delete_many(&sids, 1).await;
+
+        assert_eq!(
+            results
+                .iter()
+                .map(|(sid, _)| sid.as_str())
+                .collect::<Vec<&str>>(),
+            vec![SID_1, SID_2, SID_3]
+        );
+        assert!(results[0].1.is_ok());
+        assert!(results[1].1.as_ref().unwrap_err().is_not_found());
+        assert!(results[2].1.is_ok());
+    }
+
+    #[tokio::test]
+    async fn get_many_reports_missing_sids_as_not_found() {
+        let server = serve([
+            MockResponse::json(
+                r#"{"sid":"CH00000000000000000000000000000001","friendly_name":"Support"}"#,
+            ),
+            MockResponse::new(404).body("text/html", "Not Found"),
+        ])
+        .await;
+        let client =
+            Client::new(&test_config()).with_base_url(Domain::Conversations, &server.url());
+
+        let sids = [SID_1, SID_2]
+            .map(|sid| ConversationSid::new(sid).unwrap())
+            .to_vec();
+        let results = client.conversations().get_many(&sids, 1).await;
+
+        assert_eq!(results[0].0.as_str(), SID_1);
+        assert_eq!(
+            results[0].1.as_ref().unwrap().friendly_name.as_deref(),
+            Some("Support")
+        );
+        assert_eq!(results[1].0.as_str(), SID_2);
+        assert!(results[1].1.as_ref().unwrap_err().is_not_found());
+    }
+
+    #[test]
+    fn tolerates_missing_and_unknown_fields() {
+        let conversation: Conversation = serde_json::from_str(
+            r#"{
+                "sid": "CH11111111111111111111111111111111",
+                "state": "closed",
+                "messaging_service_sid": null,
+                "a_field_twilio_added_later": true
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(conversation.sid, "CH11111111111111111111111111111111");
+        assert_eq!(conversation.state, State::Closed);
+        assert_eq!(conversation.messaging_service_sid, None);
+        assert!(conversation.links.participants.is_empty());
+    }
+
+    #[test]
+    fn links_preferred_over_built_urls() {
+        let mut conversation = Conversation {
+            sid: String::from("CH1"),
+            chat_service_sid: String::from("IS1"),
+            ..Default::default()
+        };
+        assert_eq!(
+            conversation.participants_url(),
+            "https://conversations.twilio.com/v1/Services/IS1/Conversations/CH1/Participants"
+        );
+
+        conversation.links.participants =
+            String::from("https://conversations.twilio.com/v2/Conversations/CH1/Participants");
+        assert_eq!(
+            conversation.participants_url(),
+            "https://conversations.twilio.com/v2/Conversations/CH1/Participants"
+        );
+    }
 }
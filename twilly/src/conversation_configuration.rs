@@ -0,0 +1,138 @@
+/*!
+
+Contains Twilio account-wide Conversations configuration related functionality.
+
+*/
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{Client, TwilioError};
+
+/// Holds functions for the Conversations configuration of the account.
+pub struct ConversationConfiguration<'a> {
+    pub client: &'a Client,
+}
+
+/// Account-wide Conversations defaults.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Configuration {
+    pub account_sid: String,
+    /// SID of the Conversation Service (`IS...`) Conversations are created in by default.
+    pub default_chat_service_sid: Option<String>,
+    /// SID of the Messaging Service (`MG...`) used by Conversations by default.
+    pub default_messaging_service_sid: Option<String>,
+    /// ISO 8601 duration after which Conversations become inactive by default, e.g. `PT10M`.
+    pub default_inactive_timer: Option<String>,
+    /// ISO 8601 duration after which Conversations are closed by default, e.g. `PT10M`.
+    pub default_closed_timer: Option<String>,
+    pub url: String,
+}
+
+/// Parameters for updating the Conversations configuration. Only the provided options are
+/// changed, e.g. `UpdateParams::default().default_closed_timer("P1D")`.
+#[skip_serializing_none]
+#[derive(Default, Serialize)]
+#[serde(rename_all(serialize = "PascalCase"))]
+pub struct UpdateParams {
+    pub default_chat_service_sid: Option<String>,
+    pub default_messaging_service_sid: Option<String>,
+    /// ISO 8601 duration, e.g. `PT10M`. Must be at least 5 minutes.
+    pub default_inactive_timer: Option<String>,
+    /// ISO 8601 duration, e.g. `PT10M`. Must be at least 10 minutes.
+    pub default_closed_timer: Option<String>,
+}
+
+impl UpdateParams {
+    pub fn default_chat_service_sid(mut self, default_chat_service_sid: impl Into<String>) -> Self {
+        self.default_chat_service_sid = Some(default_chat_service_sid.into());
+        self
+    }
+
+    pub fn default_messaging_service_sid(
+        mut self,
+        default_messaging_service_sid: impl Into<String>,
+    ) -> Self {
+        self.default_messaging_service_sid = Some(default_messaging_service_sid.into());
+        self
+    }
+
+    pub fn default_inactive_timer(mut self, default_inactive_timer: impl Into<String>) -> Self {
+        self.default_inactive_timer = Some(default_inactive_timer.into());
+        self
+    }
+
+    pub fn default_closed_timer(mut self, default_closed_timer: impl Into<String>) -> Self {
+        self.default_closed_timer = Some(default_closed_timer.into());
+        self
+    }
+}
+
+impl<'a> ConversationConfiguration<'a> {
+    /// [Gets the Conversations configuration](https://www.twilio.com/docs/conversations/api/configuration-resource#fetch-a-configuration-resource)
+    ///
+    /// Applies to the whole account regardless of any Conversation Service targeted.
+    pub async fn get(&self) -> Result<Configuration, TwilioError> {
+        self.client
+            .get_resource::<Configuration>(CONFIGURATION_URL)
+            .await
+    }
+
+    /// [Updates the Conversations configuration](https://www.twilio.com/docs/conversations/api/configuration-resource#update-a-configuration-resource)
+    ///
+    /// Applies to the whole account regardless of any Conversation Service targeted.
+    pub async fn update(&self, params: UpdateParams) -> Result<Configuration, TwilioError> {
+        self.client
+            .send_request::<Configuration, UpdateParams>(
+                Method::POST,
+                CONFIGURATION_URL,
+                Some(&params),
+                None,
+            )
+            .await
+    }
+}
+
+const CONFIGURATION_URL: &str = "https://conversations.twilio.com/v1/Configuration";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{serve, test_config, MockResponse};
+    use crate::{Client, Domain};
+
+    #[tokio::test]
+    async fn conversation_configuration_updated() {
+        let server = serve([MockResponse::json(r#"{"account_sid":"AC1","default_chat_service_sid":"IS1","default_messaging_service_sid":"MG1","default_inactive_timer":"PT1H","default_closed_timer":"P1D","url":"https://conversations.twilio.com/v1/Configuration","links":{"webhooks":"https://conversations.twilio.com/v1/Configuration/Webhooks"}}"#)]).await;
+        let client =
+            Client::new(&test_config()).with_base_url(Domain::Conversations, &server.url());
+
+        let configuration = client
+            .conversations()
+            .configuration()
+            .update(
+                UpdateParams::default()
+                    .default_inactive_timer("PT1H")
+                    .default_closed_timer("P1D"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            configuration,
+            Configuration {
+                account_sid: String::from("AC1"),
+                default_chat_service_sid: Some(String::from("IS1")),
+                default_messaging_service_sid: Some(String::from("MG1")),
+                default_inactive_timer: Some(String::from("PT1H")),
+                default_closed_timer: Some(String::from("P1D")),
+                url: String::from("https://conversations.twilio.com/v1/Configuration"),
+            }
+        );
+        let request = server.request();
+        assert!(request.starts_with("POST /v1/Configuration "));
+        assert!(request.ends_with("DefaultInactiveTimer=PT1H&DefaultClosedTimer=P1D"));
+    }
+}
@@ -0,0 +1,280 @@
+/*!
+
+Contains Twilio conversation message related functionality.
+
+*/
+
+use futures::Stream;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::{
+    conversation::Conversations, idempotency_headers, util::validate_json_object, Client,
+    ErrorKind, ListPage, Page, PageMeta, TwilioError,
+};
+
+/// Holds message related functions for a single Conversation.
+pub struct ConversationMessages<'a, 'b> {
+    pub client: &'a Client,
+    /// SID of the Conversation Service (`IS...`) to target. The account's default
+    /// Conversation Service is used when `None`.
+    pub service_sid: Option<&'a str>,
+    /// SID of the Conversation (`CH...`) the messages belong to.
+    pub conversation_sid: &'b str,
+}
+
+/// Represents a page of Conversation messages from the Twilio API.
+#[allow(dead_code)]
+#[derive(Deserialize)]
+pub struct ConversationMessagePage {
+    messages: Vec<ConversationMessage>,
+    meta: PageMeta,
+}
+
+impl Page for ConversationMessagePage {
+    type Item = ConversationMessage;
+
+    fn into_list_page(self) -> ListPage<ConversationMessage> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.messages,
+        }
+    }
+}
+
+/// A message sent within a Conversation.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConversationMessage {
+    pub sid: String,
+    pub account_sid: String,
+    pub chat_service_sid: String,
+    pub conversation_sid: String,
+    /// Position of the message within the Conversation, starting from `0`.
+    pub index: u32,
+    /// Identity of the message's author. Defaults to `system` for messages sent via REST.
+    pub author: Option<String>,
+    pub body: Option<String>,
+    /// Details of any media attached to the message.
+    pub media: Option<Value>,
+    pub attributes: String,
+    /// SID of the Participant who sent the message, if any.
+    pub participant_sid: Option<String>,
+    pub date_created: String,
+    pub date_updated: Option<String>,
+    pub url: String,
+}
+
+impl ConversationMessage {
+    /// Decodes the message's `attributes` JSON string.
+    pub fn parsed_attributes(&self) -> Result<Value, serde_json::Error> {
+        serde_json::from_str(&self.attributes)
+    }
+}
+
+/// Parameters for sending a message to a Conversation. At least one of `body` or
+/// `media_sid` must be provided, e.g. `CreateParams::default().author("bot").body("Hello")`.
+#[skip_serializing_none]
+#[derive(Default, Serialize)]
+#[serde(rename_all(serialize = "PascalCase"))]
+pub struct CreateParams {
+    /// Identity of the message's author. Defaults to `system`.
+    pub author: Option<String>,
+    pub body: Option<String>,
+    /// SID of previously uploaded media (`ME...`) to attach.
+    pub media_sid: Option<String>,
+    /// JSON string of additional data to store against the message.
+    pub attributes: Option<String>,
+    /// Sent as the `I-Twilio-Idempotency-Token` header, see `IDEMPOTENCY_TOKEN_HEADER`.
+    #[serde(skip)]
+    pub idempotency_token: Option<String>,
+}
+
+impl CreateParams {
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    pub fn media_sid(mut self, media_sid: impl Into<String>) -> Self {
+        self.media_sid = Some(media_sid.into());
+        self
+    }
+
+    pub fn attributes(mut self, attributes: impl Into<String>) -> Self {
+        self.attributes = Some(attributes.into());
+        self
+    }
+
+    pub fn idempotency_token(mut self, idempotency_token: impl Into<String>) -> Self {
+        self.idempotency_token = Some(idempotency_token.into());
+        self
+    }
+}
+
+/// Parameters for updating a message within a Conversation.
+#[skip_serializing_none]
+#[derive(Default, Serialize)]
+#[serde(rename_all(serialize = "PascalCase"))]
+pub struct UpdateParams {
+    pub author: Option<String>,
+    pub body: Option<String>,
+    /// JSON string of additional data to store against the message.
+    pub attributes: Option<String>,
+}
+
+impl<'a, 'b> ConversationMessages<'a, 'b> {
+    /// [Sends a Conversation Message](https://www.twilio.com/docs/conversations/api/conversation-message-resource#create-a-conversationmessage-resource)
+    ///
+    /// Sends a message to the Conversation provided to `messages()`.
+    pub async fn create(&self, params: CreateParams) -> Result<ConversationMessage, TwilioError> {
+        if params.body.is_none() && params.media_sid.is_none() {
+            return Err(TwilioError {
+                kind: ErrorKind::ValidationError(String::from(
+                    "A message body or media SID must be provided",
+                )),
+            });
+        }
+        if let Some(attributes) = &params.attributes {
+            validate_json_object("Attributes", attributes)?;
+        }
+
+        let headers = idempotency_headers(params.idempotency_token.as_deref())?;
+        self.client
+            .send_request::<ConversationMessage, CreateParams>(
+                Method::POST,
+                &self.messages_url(),
+                Some(&params),
+                headers,
+            )
+            .await
+    }
+
+    /// [Lists Conversation Messages](https://www.twilio.com/docs/conversations/api/conversation-message-resource#read-multiple-conversationmessage-resources)
+    ///
+    /// Messages are listed oldest first and will be _eagerly_ paged until all retrieved.
+    /// See `list_paged` to page lazily.
+    pub async fn list(&self) -> Result<Vec<ConversationMessage>, TwilioError> {
+        self.client.collect_pages(self.list_paged()).await
+    }
+
+    /// Lists messages a page at a time, see [`ListPage`].
+    pub fn list_paged(
+        &self,
+    ) -> impl Stream<Item = Result<ListPage<ConversationMessage>, TwilioError>> + '_ {
+        self.client.list_pages::<ConversationMessagePage, ()>(
+            format!("{}?PageSize=50", self.messages_url()),
+            None,
+        )
+    }
+
+    /// [Gets a Conversation Message](https://www.twilio.com/docs/conversations/api/conversation-message-resource#fetch-a-conversationmessage-resource)
+    ///
+    /// Takes in the `sid` (`IM...`) of the message to fetch.
+    pub async fn get(&self, sid: &str) -> Result<ConversationMessage, TwilioError> {
+        self.client
+            .get_resource::<ConversationMessage>(&format!("{}/{}", self.messages_url(), sid))
+            .await
+    }
+
+    /// [Updates a Conversation Message](https://www.twilio.com/docs/conversations/api/conversation-message-resource#update-a-conversationmessage-resource)
+    ///
+    /// Takes in the `sid` (`IM...`) of the message and updates it with the provided properties.
+    pub async fn update(
+        &self,
+        sid: &str,
+        params: UpdateParams,
+    ) -> Result<ConversationMessage, TwilioError> {
+        if let Some(attributes) = &params.attributes {
+            validate_json_object("Attributes", attributes)?;
+        }
+
+        self.client
+            .send_request::<ConversationMessage, UpdateParams>(
+                Method::POST,
+                &format!("{}/{}", self.messages_url(), sid),
+                Some(&params),
+                None,
+            )
+            .await
+    }
+
+    /// [Deletes a Conversation Message](https://www.twilio.com/docs/conversations/api/conversation-message-resource#delete-a-conversationmessage-resource)
+    ///
+    /// Takes in the `sid` (`IM...`) of the message and **deletes** it.
+    pub async fn delete(&self, sid: &str) -> Result<(), TwilioError> {
+        self.client
+            .send_request_and_ignore_response::<()>(
+                Method::DELETE,
+                &format!("{}/{}", self.messages_url(), sid),
+                None,
+                None,
+            )
+            .await
+    }
+
+    fn messages_url(&self) -> String {
+        let conversations = Conversations {
+            client: self.client,
+            service_sid: self.service_sid,
+        };
+
+        format!(
+            "{}/{}/Messages",
+            conversations.conversations_url(),
+            self.conversation_sid
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{serve, test_config, MockResponse};
+    use crate::{Client, Domain};
+
+    #[tokio::test]
+    async fn conversation_message_created() {
+        let server = serve([MockResponse::json(r#"{"sid":"IM1","conversation_sid":"CH1","index":0,"author":"bot","body":"Hello","attributes":"{}","date_created":"2024-01-01T00:00:00Z"}"#).status(201)]).await;
+        let client =
+            Client::new(&test_config()).with_base_url(Domain::Conversations, &server.url());
+
+        let message = client
+            .conversations()
+            .messages("CH1")
+            .create(CreateParams::default().author("bot").body("Hello"))
+            .await
+            .unwrap();
+
+        assert_eq!(message.sid, "IM1");
+        assert_eq!(message.body.as_deref(), Some("Hello"));
+        let request = server.request();
+        assert!(request.starts_with("POST /v1/Conversations/CH1/Messages "));
+        assert!(request.ends_with("Author=bot&Body=Hello"));
+    }
+
+    #[tokio::test]
+    async fn conversation_message_without_content_rejected() {
+        let client = Client::new(&test_config());
+
+        let result = client
+            .conversations()
+            .messages("CH1")
+            .create(CreateParams::default().author("bot"))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(TwilioError {
+                kind: ErrorKind::ValidationError(_)
+            })
+        ));
+    }
+}
@@ -0,0 +1,437 @@
+/*!
+
+Contains Twilio conversation scoped webhook related functionality.
+
+*/
+
+use futures::Stream;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, Display, EnumIter, EnumString};
+
+use crate::{
+    conversation::Conversations, Client, ErrorKind, ListPage, Page, PageMeta, TwilioError,
+};
+
+/// Holds scoped webhook related functions for a single Conversation.
+pub struct ConversationWebhooks<'a, 'b> {
+    pub client: &'a Client,
+    /// SID of the Conversation Service (`IS...`) to target. The account's default
+    /// Conversation Service is used when `None`.
+    pub service_sid: Option<&'a str>,
+    /// SID of the Conversation (`CH...`) the webhooks belong to.
+    pub conversation_sid: &'b str,
+}
+
+/// Represents a page of Conversation scoped webhooks from the Twilio API.
+#[allow(dead_code)]
+#[derive(Deserialize)]
+pub struct ConversationWebhookPage {
+    webhooks: Vec<ConversationWebhook>,
+    meta: PageMeta,
+}
+
+impl Page for ConversationWebhookPage {
+    type Item = ConversationWebhook;
+
+    fn into_list_page(self) -> ListPage<ConversationWebhook> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.webhooks,
+        }
+    }
+}
+
+/// A webhook scoped to a single Conversation.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConversationWebhook {
+    pub sid: String,
+    pub account_sid: String,
+    pub chat_service_sid: String,
+    pub conversation_sid: String,
+    pub target: Target,
+    pub configuration: Configuration,
+    pub date_created: String,
+    pub date_updated: String,
+    pub url: String,
+}
+
+/// Where a scoped webhook sends Conversation events.
+#[derive(
+    AsRefStr,
+    Clone,
+    Display,
+    Default,
+    Debug,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+    PartialEq,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum Target {
+    /// Events are sent to the configured `url`.
+    #[default]
+    Webhook,
+    /// Events are sent to the configured `url` when a message matches one of the `triggers`.
+    Trigger,
+    /// Events are sent to the Studio Flow with the configured `flow_sid`.
+    Studio,
+}
+
+/// HTTP method Twilio uses to call a webhook URL.
+#[derive(
+    AsRefStr,
+    Clone,
+    Display,
+    Default,
+    Debug,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+    PartialEq,
+)]
+#[serde(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum WebhookMethod {
+    Get,
+    #[default]
+    Post,
+}
+
+/// Configuration of a scoped webhook. Which fields are set depends on the `target`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Configuration {
+    pub url: Option<String>,
+    pub method: Option<WebhookMethod>,
+    /// Events sent to the webhook, e.g. `onMessageAdded`.
+    pub filters: Vec<String>,
+    /// Words in a message body that trigger a `trigger` webhook.
+    pub triggers: Vec<String>,
+    /// SID of the Studio Flow (`FW...`) of a `studio` webhook.
+    pub flow_sid: Option<String>,
+    /// Number of messages to replay to a newly created `studio` webhook.
+    pub replay_after: Option<u32>,
+}
+
+/// Parameters for creating a scoped webhook, e.g.
+/// `CreateParams::default().url("https://example.com").filter("onMessageAdded")`.
+///
+/// A `webhook` or `trigger` target requires a `url` and a `studio` target requires a `flow_sid`.
+#[derive(Default)]
+pub struct CreateParams {
+    pub target: Target,
+    pub url: Option<String>,
+    pub method: Option<WebhookMethod>,
+    /// Events to send to the webhook, e.g. `onMessageAdded`.
+    pub filters: Vec<String>,
+    /// Words in a message body that trigger a `trigger` webhook.
+    pub triggers: Vec<String>,
+    /// SID of the Studio Flow (`FW...`) of a `studio` webhook.
+    pub flow_sid: Option<String>,
+    /// Number of messages to replay to a `studio` webhook once created.
+    pub replay_after: Option<u32>,
+}
+
+impl CreateParams {
+    pub fn target(mut self, target: Target) -> Self {
+        self.target = target;
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    pub fn method(mut self, method: WebhookMethod) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Adds an event to send to the webhook.
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.filters.push(filter.into());
+        self
+    }
+
+    /// Adds a word that triggers a `trigger` webhook.
+    pub fn trigger(mut self, trigger: impl Into<String>) -> Self {
+        self.triggers.push(trigger.into());
+        self
+    }
+
+    pub fn flow_sid(mut self, flow_sid: impl Into<String>) -> Self {
+        self.flow_sid = Some(flow_sid.into());
+        self
+    }
+
+    pub fn replay_after(mut self, replay_after: u32) -> Self {
+        self.replay_after = Some(replay_after);
+        self
+    }
+
+    // Form fields of the parameters. Twilio expects a repeated field for each filter and
+    // trigger which can't be derived, so the fields are listed as pairs.
+    fn form(&self) -> Vec<(&'static str, String)> {
+        let mut form = vec![("Target", self.target.to_string())];
+        form.extend(configuration_form(
+            self.url.as_deref(),
+            self.method.as_ref(),
+            &self.filters,
+            &self.triggers,
+            self.flow_sid.as_deref(),
+        ));
+        if let Some(replay_after) = self.replay_after {
+            form.push(("Configuration.ReplayAfter", replay_after.to_string()));
+        }
+
+        form
+    }
+}
+
+/// Parameters for updating a scoped webhook. Only the provided options are changed, e.g.
+/// `UpdateParams::default().url("https://example.com")`.
+#[derive(Default)]
+pub struct UpdateParams {
+    pub url: Option<String>,
+    pub method: Option<WebhookMethod>,
+    /// Events to send to the webhook, replacing the existing filters.
+    pub filters: Vec<String>,
+    /// Words in a message body that trigger a `trigger` webhook, replacing the existing triggers.
+    pub triggers: Vec<String>,
+    pub flow_sid: Option<String>,
+}
+
+impl UpdateParams {
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    pub fn method(mut self, method: WebhookMethod) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Adds an event to send to the webhook.
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.filters.push(filter.into());
+        self
+    }
+
+    /// Adds a word that triggers a `trigger` webhook.
+    pub fn trigger(mut self, trigger: impl Into<String>) -> Self {
+        self.triggers.push(trigger.into());
+        self
+    }
+
+    pub fn flow_sid(mut self, flow_sid: impl Into<String>) -> Self {
+        self.flow_sid = Some(flow_sid.into());
+        self
+    }
+
+    fn form(&self) -> Vec<(&'static str, String)> {
+        configuration_form(
+            self.url.as_deref(),
+            self.method.as_ref(),
+            &self.filters,
+            &self.triggers,
+            self.flow_sid.as_deref(),
+        )
+    }
+}
+
+// `Configuration.*` form fields shared by create and update. Unset options are left out.
+fn configuration_form(
+    url: Option<&str>,
+    method: Option<&WebhookMethod>,
+    filters: &[String],
+    triggers: &[String],
+    flow_sid: Option<&str>,
+) -> Vec<(&'static str, String)> {
+    let mut form = Vec::new();
+    if let Some(url) = url {
+        form.push(("Configuration.Url", url.to_string()));
+    }
+    if let Some(method) = method {
+        form.push(("Configuration.Method", method.to_string()));
+    }
+    for filter in filters {
+        form.push(("Configuration.Filters", filter.clone()));
+    }
+    for trigger in triggers {
+        form.push(("Configuration.Triggers", trigger.clone()));
+    }
+    if let Some(flow_sid) = flow_sid {
+        form.push(("Configuration.FlowSid", flow_sid.to_string()));
+    }
+
+    form
+}
+
+impl<'a, 'b> ConversationWebhooks<'a, 'b> {
+    /// [Creates a Conversation Scoped Webhook](https://www.twilio.com/docs/conversations/api/conversation-scoped-webhook-resource#create-a-conversationscopedwebhook-resource)
+    ///
+    /// Creates a webhook on the Conversation provided to `webhooks()`.
+    pub async fn create(&self, params: CreateParams) -> Result<ConversationWebhook, TwilioError> {
+        match params.target {
+            Target::Webhook | Target::Trigger if params.url.is_none() => {
+                return Err(TwilioError {
+                    kind: ErrorKind::ValidationError(format!(
+                        "A URL must be provided for a '{}' webhook",
+                        params.target
+                    )),
+                });
+            }
+            Target::Studio if params.flow_sid.is_none() => {
+                return Err(TwilioError {
+                    kind: ErrorKind::ValidationError(String::from(
+                        "A Studio Flow SID must be provided for a 'studio' webhook",
+                    )),
+                });
+            }
+            _ => (),
+        }
+
+        self.client
+            .send_request::<ConversationWebhook, Vec<(&str, String)>>(
+                Method::POST,
+                &self.webhooks_url(),
+                Some(&params.form()),
+                None,
+            )
+            .await
+    }
+
+    /// [Lists Conversation Scoped Webhooks](https://www.twilio.com/docs/conversations/api/conversation-scoped-webhook-resource#read-multiple-conversationscopedwebhook-resources)
+    ///
+    /// Webhooks will be _eagerly_ paged until all retrieved. See `list_paged` to page lazily.
+    pub async fn list(&self) -> Result<Vec<ConversationWebhook>, TwilioError> {
+        self.client.collect_pages(self.list_paged()).await
+    }
+
+    /// Lists webhooks a page at a time, see [`ListPage`].
+    pub fn list_paged(
+        &self,
+    ) -> impl Stream<Item = Result<ListPage<ConversationWebhook>, TwilioError>> + '_ {
+        self.client.list_pages::<ConversationWebhookPage, ()>(
+            format!("{}?PageSize=50", self.webhooks_url()),
+            None,
+        )
+    }
+
+    /// [Gets a Conversation Scoped Webhook](https://www.twilio.com/docs/conversations/api/conversation-scoped-webhook-resource#fetch-a-conversationscopedwebhook-resource)
+    ///
+    /// Takes in the `sid` (`WH...`) of the webhook to fetch.
+    pub async fn get(&self, sid: &str) -> Result<ConversationWebhook, TwilioError> {
+        self.client
+            .get_resource::<ConversationWebhook>(&format!("{}/{}", self.webhooks_url(), sid))
+            .await
+    }
+
+    /// [Updates a Conversation Scoped Webhook](https://www.twilio.com/docs/conversations/api/conversation-scoped-webhook-resource#update-a-conversationscopedwebhook-resource)
+    ///
+    /// Takes in the `sid` (`WH...`) of the webhook and updates its configuration with the
+    /// provided properties. The `target` of a webhook can't be changed.
+    pub async fn update(
+        &self,
+        sid: &str,
+        params: UpdateParams,
+    ) -> Result<ConversationWebhook, TwilioError> {
+        self.client
+            .send_request::<ConversationWebhook, Vec<(&str, String)>>(
+                Method::POST,
+                &format!("{}/{}", self.webhooks_url(), sid),
+                Some(&params.form()),
+                None,
+            )
+            .await
+    }
+
+    /// [Deletes a Conversation Scoped Webhook](https://www.twilio.com/docs/conversations/api/conversation-scoped-webhook-resource#delete-a-conversationscopedwebhook-resource)
+    ///
+    /// Takes in the `sid` (`WH...`) of the webhook and **deletes** it.
+    pub async fn delete(&self, sid: &str) -> Result<(), TwilioError> {
+        self.client
+            .send_request_and_ignore_response::<()>(
+                Method::DELETE,
+                &format!("{}/{}", self.webhooks_url(), sid),
+                None,
+                None,
+            )
+            .await
+    }
+
+    fn webhooks_url(&self) -> String {
+        let conversations = Conversations {
+            client: self.client,
+            service_sid: self.service_sid,
+        };
+
+        format!(
+            "{}/{}/Webhooks",
+            conversations.conversations_url(),
+            self.conversation_sid
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{serve, test_config, MockResponse};
+    use crate::{Client, Domain};
+
+    #[tokio::test]
+    async fn conversation_webhook_created_with_filters() {
+        let server = serve([MockResponse::json(r#"{"sid":"WH1","conversation_sid":"CH1","target":"webhook","configuration":{"url":"https://example.com/events","method":"POST","filters":["onMessageAdded","onParticipantAdded"]},"date_created":"2024-01-01T00:00:00Z"}"#).status(201)]).await;
+        let client =
+            Client::new(&test_config()).with_base_url(Domain::Conversations, &server.url());
+
+        let webhook = client
+            .conversations()
+            .webhooks("CH1")
+            .create(
+                CreateParams::default()
+                    .url("https://example.com/events")
+                    .filter("onMessageAdded")
+                    .filter("onParticipantAdded"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(webhook.sid, "WH1");
+        assert_eq!(webhook.target, Target::Webhook);
+        assert_eq!(webhook.configuration.method, Some(WebhookMethod::Post));
+        assert_eq!(webhook.date_created, "2024-01-01T00:00:00Z");
+        let request = server.request();
+        assert!(request.starts_with("POST /v1/Conversations/CH1/Webhooks "));
+        assert!(request.ends_with(
+            "Target=webhook&Configuration.Url=https%3A%2F%2Fexample.com%2Fevents&Configuration.Filters=onMessageAdded&Configuration.Filters=onParticipantAdded"
+        ));
+    }
+
+    #[tokio::test]
+    async fn studio_webhook_without_flow_rejected() {
+        let client = Client::new(&test_config());
+
+        let result = client
+            .conversations()
+            .webhooks("CH1")
+            .create(CreateParams::default().target(Target::Studio))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(TwilioError {
+                kind: ErrorKind::ValidationError(_)
+            })
+        ));
+    }
+}
@@ -0,0 +1,188 @@
+/*!
+
+Contains Twilio message related functionality.
+
+*/
+
+use std::fmt;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{Client, ErrorKind, TwilioError};
+
+/// Maximum characters of a single GSM-7 encoded SMS segment.
+const GSM_SEGMENT_LENGTH: usize = 160;
+/// Maximum characters of a single UCS-2 encoded SMS segment.
+const UCS2_SEGMENT_LENGTH: usize = 70;
+
+/// Holds message related functions accessible
+/// on the client.
+pub struct Messages<'a> {
+    pub client: &'a Client,
+}
+
+/// Details related to a specific message.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Message {
+    pub sid: String,
+    pub account_sid: String,
+    pub messaging_service_sid: Option<String>,
+    pub from: Option<String>,
+    pub to: String,
+    pub body: Option<String>,
+    pub status: String,
+    pub direction: String,
+    pub num_segments: String,
+    pub num_media: String,
+    pub error_code: Option<i32>,
+    pub error_message: Option<String>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub date_sent: Option<String>,
+    pub uri: String,
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} - {}", self.sid, self.status)
+    }
+}
+
+/// Parameters for sending a Message. At least one of `body` or `media_url`
+/// must be provided as well as exactly one sender, either `from` or
+/// `messaging_service_sid`.
+#[skip_serializing_none]
+#[derive(Default, Serialize)]
+#[serde(rename_all(serialize = "PascalCase"))]
+pub struct CreateParams {
+    /// The recipient's phone number (E.164 format) or channel address.
+    pub to: String,
+    /// The sender's phone number (E.164 format) or channel address.
+    pub from: Option<String>,
+    /// The SID of the Messaging Service to send from. Twilio will select
+    /// a sender from the Service's pool.
+    pub messaging_service_sid: Option<String>,
+    pub body: Option<String>,
+    /// A publicly accessible URL of media to include in the Message.
+    pub media_url: Option<String>,
+}
+
+impl CreateParams {
+    /// Whether the body will be split over multiple SMS segments, each billed separately.
+    /// Not an error, so left for callers to warn about before sending.
+    pub fn exceeds_single_segment(&self) -> bool {
+        self.body.as_deref().is_some_and(exceeds_single_segment)
+    }
+}
+
+impl<'a> Messages<'a> {
+    /// [Creates a Message](https://www.twilio.com/docs/messaging/api/message-resource#create-a-message-resource)
+    ///
+    /// Sends a Message from the authenticated account with the provided parameters.
+    ///
+    /// Parameters are validated before sending: one of `body` or `media_url` must be present
+    /// and only one of `from` or `messaging_service_sid` can be set. Check
+    /// `CreateParams::exceeds_single_segment` to warn about bodies sent as multiple segments.
+    pub async fn create(&self, params: CreateParams) -> Result<Message, TwilioError> {
+        validate_create_params(&params)?;
+
+        self.client
+            .send_request::<Message, CreateParams>(
+                Method::POST,
+                &format!(
+                    "https://api.twilio.com/2010-04-01/Accounts/{}/Messages.json",
+                    self.client.config.account_sid
+                ),
+                Some(&params),
+                None,
+            )
+            .await
+    }
+}
+
+// Validates the content and sender of a message are present before
+// reaching Twilio.
+fn validate_create_params(params: &CreateParams) -> Result<(), TwilioError> {
+    let has_body = params.body.as_ref().is_some_and(|body| !body.is_empty());
+    let has_media = params
+        .media_url
+        .as_ref()
+        .is_some_and(|media_url| !media_url.is_empty());
+
+    if !has_body && !has_media {
+        return Err(TwilioError {
+            kind: ErrorKind::ValidationError(String::from(
+                "A message requires either a body or media URL",
+            )),
+        });
+    }
+
+    match (&params.from, &params.messaging_service_sid) {
+        (Some(_), Some(_)) => Err(TwilioError {
+            kind: ErrorKind::ValidationError(String::from(
+                "Only one of from or messaging service SID can be provided",
+            )),
+        }),
+        (None, None) => Err(TwilioError {
+            kind: ErrorKind::ValidationError(String::from(
+                "A message requires either a from number or messaging service SID",
+            )),
+        }),
+        _ => Ok(()),
+    }
+}
+
+// Determines whether the body would be split into multiple SMS segments. Bodies
+// containing only ASCII characters are treated as GSM-7 encoded, anything else
+// as UCS-2. This is an approximation as the GSM-7 character set is not strictly ASCII.
+fn exceeds_single_segment(body: &str) -> bool {
+    let segment_length = if body.is_ascii() {
+        GSM_SEGMENT_LENGTH
+    } else {
+        UCS2_SEGMENT_LENGTH
+    };
+
+    body.chars().count() > segment_length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requires_body_or_media() {
+        let params = CreateParams {
+            to: String::from("+447000000000"),
+            from: Some(String::from("+447000000001")),
+            ..Default::default()
+        };
+
+        assert!(validate_create_params(&params).is_err());
+    }
+
+    #[test]
+    fn requires_a_single_sender() {
+        let mut params = CreateParams {
+            to: String::from("+447000000000"),
+            body: Some(String::from("Hello")),
+            ..Default::default()
+        };
+        assert!(validate_create_params(&params).is_err());
+
+        params.from = Some(String::from("+447000000001"));
+        params.messaging_service_sid = Some(String::from("MG11111111111111111111111111111111"));
+        assert!(validate_create_params(&params).is_err());
+
+        params.messaging_service_sid = None;
+        assert!(validate_create_params(&params).is_ok());
+    }
+
+    #[test]
+    fn segment_length_by_encoding() {
+        assert!(!exceeds_single_segment(&"a".repeat(160)));
+        assert!(exceeds_single_segment(&"a".repeat(161)));
+        assert!(exceeds_single_segment(&"é".repeat(71)));
+    }
+}
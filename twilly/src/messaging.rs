@@ -0,0 +1,56 @@
+/*!
+
+Contains Twilio Messaging Service related functionality.
+
+*/
+pub mod services;
+
+use crate::Client;
+
+use self::services::{Service, Services};
+
+/// Holds Messaging related functions accessible
+/// on the client.
+pub struct Messaging<'a> {
+    pub client: &'a Client,
+}
+
+impl<'a> Messaging<'a> {
+    /// Actions relating to a known Messaging Service.
+    ///
+    /// Takes in the SID of the Service to perform actions against.
+    pub fn service<'b: 'a>(&'a self, sid: &'b str) -> Service {
+        Service {
+            client: self.client,
+            sid,
+        }
+    }
+
+    /// General Messaging Service actions.
+    pub fn services(&'a self) -> Services {
+        Services {
+            client: self.client,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock::{serve, test_config, MockResponse};
+    use crate::{Client, Domain};
+
+    #[tokio::test]
+    async fn messaging_services_listed_and_fetched() {
+        let server = serve([MockResponse::json(r#"{"services":[{"sid":"MG1","account_sid":"AC1","friendly_name":"Notifications","inbound_request_url":"https://example.com/inbound","inbound_method":"POST","fallback_url":null,"status_callback":"https://example.com/status","us_app_to_person_registered":true,"date_created":"","date_updated":"","url":""}],"meta":{"page":0,"page_size":50,"first_page_url":"","previous_page_url":null,"next_page_url":null,"url":"","next_page_token":null,"key":"services"}}"#), MockResponse::json(r#"{"sid":"MG1","account_sid":"AC1","friendly_name":"Notifications","inbound_request_url":"https://example.com/inbound","inbound_method":"POST","fallback_url":null,"status_callback":"https://example.com/status","us_app_to_person_registered":true,"date_created":"","date_updated":"","url":""}"#)]).await;
+        let client = Client::new(&test_config()).with_base_url(Domain::Messaging, &server.url());
+
+        let services = client.messaging().services().list().await.unwrap();
+        assert_eq!(services.len(), 1);
+        assert_eq!(services[0].friendly_name, "Notifications");
+        assert!(services[0].us_app_to_person_registered);
+        assert_eq!(services[0].fallback_url, None);
+
+        let service = client.messaging().service("MG1").get().await.unwrap();
+        assert_eq!(service, services[0]);
+    }
+}
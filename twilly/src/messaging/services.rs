@@ -0,0 +1,88 @@
+/*!
+
+Contains Twilio Messaging Service related functionality.
+
+*/
+
+use crate::{Client, ListPage, Page, PageMeta, TwilioError};
+use serde::{Deserialize, Serialize};
+
+/// Represents a page of Messaging Services from the Twilio API.
+#[allow(dead_code)]
+#[derive(Deserialize)]
+pub struct MessagingServicePage {
+    services: Vec<MessagingService>,
+    meta: PageMeta,
+}
+
+impl Page for MessagingServicePage {
+    type Item = MessagingService;
+
+    fn into_list_page(self) -> ListPage<MessagingService> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.services,
+        }
+    }
+}
+
+/// A Messaging Service resource.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct MessagingService {
+    pub sid: String,
+    pub account_sid: String,
+    pub friendly_name: String,
+    /// URL Twilio requests when a message is received by a sender of the Service.
+    pub inbound_request_url: Option<String>,
+    /// HTTP method used to request the `inbound_request_url`.
+    pub inbound_method: Option<String>,
+    /// URL Twilio requests when the `inbound_request_url` fails.
+    pub fallback_url: Option<String>,
+    /// URL Twilio requests with the status of messages sent by the Service.
+    pub status_callback: Option<String>,
+    /// Whether the Service is registered for US A2P 10DLC messaging.
+    pub us_app_to_person_registered: bool,
+    pub date_created: String,
+    pub date_updated: String,
+    pub url: String,
+}
+
+pub struct Services<'a> {
+    pub client: &'a Client,
+}
+
+impl<'a> Services<'a> {
+    /// [Lists Messaging Services](https://www.twilio.com/docs/messaging/api/service-resource#read-multiple-service-resources)
+    ///
+    /// List Messaging Services existing on the Twilio account.
+    ///
+    /// Services will be _eagerly_ paged until all retrieved.
+    pub async fn list(&self) -> Result<Vec<MessagingService>, TwilioError> {
+        self.client
+            .paginate::<MessagingServicePage, ()>(
+                String::from("https://messaging.twilio.com/v1/Services?PageSize=50"),
+                None,
+            )
+            .await
+    }
+}
+
+pub struct Service<'a, 'b> {
+    pub client: &'a Client,
+    pub sid: &'b str,
+}
+
+impl<'a, 'b> Service<'a, 'b> {
+    /// [Gets a Messaging Service](https://www.twilio.com/docs/messaging/api/service-resource#fetch-a-service-resource)
+    ///
+    /// Fetches the Messaging Service provided to the `Service()`.
+    pub async fn get(&self) -> Result<MessagingService, TwilioError> {
+        self.client
+            .get_resource::<MessagingService>(&format!(
+                "https://messaging.twilio.com/v1/Services/{}",
+                self.sid
+            ))
+            .await
+    }
+}
@@ -0,0 +1,186 @@
+//! A local HTTP server standing in for Twilio in tests. Available to other crates in
+//! the workspace through the `test-util` feature.
+
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use reqwest::StatusCode;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+use crate::{Client, Domain, TwilioConfig};
+
+/// Credentials accepted by the mock, they are never checked.
+pub fn test_config() -> TwilioConfig {
+    TwilioConfig::build(
+        String::from("AC11111111111111111111111111111111"),
+        String::from("11111111111111111111111111111111"),
+    )
+    .unwrap()
+}
+
+/// A canned HTTP response. The `Content-Length` is worked out from the body.
+pub struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+    content_length: Option<usize>,
+}
+
+impl MockResponse {
+    /// An empty response with `status`, e.g. `204 No Content`.
+    pub fn new(status: u16) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: String::new(),
+            content_length: None,
+        }
+    }
+
+    /// A `200 OK` response with `body` as JSON.
+    pub fn json(body: &str) -> Self {
+        Self::new(200).body("application/json", body)
+    }
+
+    pub fn status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn body(mut self, content_type: &str, body: &str) -> Self {
+        self.body = body.to_string();
+        self.header("Content-Type", content_type)
+    }
+
+    /// Promises `content_length` bytes of body regardless of how many are sent,
+    /// e.g. to simulate a connection closed mid-response.
+    pub fn content_length(mut self, content_length: usize) -> Self {
+        self.content_length = Some(content_length);
+        self
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let reason = StatusCode::from_u16(self.status)
+            .ok()
+            .and_then(|status| status.canonical_reason())
+            .unwrap_or_default();
+        let mut response = format!("HTTP/1.1 {} {}\r\n", self.status, reason);
+        for (name, value) in &self.headers {
+            response.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        response.push_str(&format!(
+            "Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.content_length.unwrap_or(self.body.len()),
+            self.body
+        ));
+        response.into_bytes()
+    }
+}
+
+/// A local server answering each connection with a canned response, recording the
+/// requests received.
+pub struct MockServer {
+    pub address: SocketAddr,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockServer {
+    /// The base URL to send requests to, e.g. for `Client::with_base_url`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// A client sending requests for `domain` to this server.
+    pub fn client(&self, domain: Domain) -> Client {
+        Client::new(&test_config()).with_base_url(domain, &self.url())
+    }
+
+    /// Every request received so far, including headers and body, in the order received.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// The first request received.
+    pub fn request(&self) -> String {
+        self.requests()
+            .into_iter()
+            .next()
+            .expect("No request was received")
+    }
+}
+
+/// Serves each response in turn, one per connection, on a local port.
+pub async fn serve(responses: impl IntoIterator<Item = MockResponse>) -> MockServer {
+    let responses: Vec<Vec<u8>> = responses.into_iter().map(|r| r.to_bytes()).collect();
+    start(move |index| responses.get(index).cloned()).await
+}
+
+/// Serves the same response to every connection on a local port.
+pub async fn serve_repeatedly(response: MockResponse) -> MockServer {
+    let response = response.to_bytes();
+    start(move |_| Some(response.clone())).await
+}
+
+async fn start(
+    mut response_for: impl FnMut(usize) -> Option<Vec<u8>> + Send + 'static,
+) -> MockServer {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorder = requests.clone();
+
+    tokio::spawn(async move {
+        let mut index = 0;
+        while let Some(response) = response_for(index) {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let request = read_request(&mut socket).await;
+            recorder.lock().unwrap().push(request);
+            socket.write_all(&response).await.unwrap();
+            index += 1;
+        }
+    });
+
+    MockServer { address, requests }
+}
+
+// Reads until the headers and the body they describe have been received.
+async fn read_request(socket: &mut TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buffer = [0; 1024];
+
+    loop {
+        let length = socket.read(&mut buffer).await.unwrap_or_default();
+        request.extend_from_slice(&buffer[..length]);
+        if length == 0 {
+            break;
+        }
+
+        let received = String::from_utf8_lossy(&request);
+        let Some((head, body)) = received.split_once("\r\n\r\n") else {
+            continue;
+        };
+        let content_length = head
+            .lines()
+            .find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                name.eq_ignore_ascii_case("content-length")
+                    .then(|| value.trim().parse::<usize>().ok())?
+            })
+            .unwrap_or_default();
+        if body.len() >= content_length {
+            break;
+        }
+    }
+
+    String::from_utf8_lossy(&request).into_owned()
+}
@@ -0,0 +1,102 @@
+/*!
+
+Contains Twilio conversation participant related functionality.
+
+*/
+
+use serde::{Deserialize, Serialize};
+
+use crate::{conversation::Conversations, Client, ListPage, Page, PageMeta, TwilioError};
+
+/// Holds participant related functions for a single Conversation.
+pub struct Participants<'a, 'b> {
+    pub client: &'a Client,
+    /// SID of the Conversation Service (`IS...`) to target. The account's default
+    /// Conversation Service is used when `None`.
+    pub service_sid: Option<&'a str>,
+    /// SID of the Conversation (`CH...`) the participants belong to.
+    pub conversation_sid: &'b str,
+}
+
+/// Represents a page of participants from the Twilio API.
+#[allow(dead_code)]
+#[derive(Deserialize)]
+pub struct ParticipantPage {
+    participants: Vec<Participant>,
+    meta: PageMeta,
+}
+
+impl Page for ParticipantPage {
+    type Item = Participant;
+
+    fn into_list_page(self) -> ListPage<Participant> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.participants,
+        }
+    }
+}
+
+/// Details related to a participant of a Conversation.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Participant {
+    pub sid: String,
+    pub account_sid: String,
+    pub chat_service_sid: String,
+    pub conversation_sid: String,
+    /// Identity of a Conversations SDK participant. `None` for participants using
+    /// a messaging binding, e.g. SMS.
+    pub identity: Option<String>,
+    pub attributes: String,
+    pub messaging_binding: Option<MessagingBinding>,
+    pub role_sid: Option<String>,
+    pub date_created: String,
+    pub date_updated: String,
+    pub url: String,
+    pub last_read_message_index: Option<u32>,
+    pub last_read_timestamp: Option<String>,
+}
+
+/// How a non SDK participant communicates with the Conversation.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MessagingBinding {
+    /// The participant's address, e.g. their phone number.
+    pub address: Option<String>,
+    /// The address messages are sent to the participant from.
+    pub proxy_address: Option<String>,
+    /// The address used to identify the participant in group MMS.
+    pub projected_address: Option<String>,
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+}
+
+impl<'a, 'b> Participants<'a, 'b> {
+    /// [Lists Conversation Participants](https://www.twilio.com/docs/conversations/api/conversation-participant-resource#read-multiple-conversationparticipant-resources)
+    ///
+    /// This will eagerly fetch *all* participants of the Conversation provided to `participants()`.
+    pub async fn list(&self) -> Result<Vec<Participant>, TwilioError> {
+        let conversations = Conversations {
+            client: self.client,
+            service_sid: self.service_sid,
+        };
+
+        self.list_from(&format!(
+            "{}/{}/Participants",
+            conversations.conversations_url(),
+            self.conversation_sid
+        ))
+        .await
+    }
+
+    // Lists the participants at `participants_url`, e.g. taken from a Conversation's `links`.
+    pub(crate) async fn list_from(
+        &self,
+        participants_url: &str,
+    ) -> Result<Vec<Participant>, TwilioError> {
+        self.client
+            .paginate::<ParticipantPage, ()>(format!("{}?PageSize=50", participants_url), None)
+            .await
+    }
+}
@@ -4,18 +4,20 @@ Contains Twilio participant conversation related functionality.
 
 */
 
-use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    conversation::{State, Timers},
-    Client, PageMeta, TwilioError,
+    conversation::{ConversationTimers, State},
+    Client, ListPage, Page, PageMeta, TwilioError,
 };
 
 /// Holds participant conversation related functions accessible
 /// on the client.
 pub struct ParticipantConversations<'a> {
     pub client: &'a Client,
+    /// SID of the Conversation Service (`IS...`) to target. The account's default
+    /// Conversation Service is used when `None`.
+    pub service_sid: Option<&'a str>,
 }
 
 /// Represents a page of participant conversations from the Twilio API.
@@ -26,6 +28,17 @@ pub struct ParticipantConversationPage {
     meta: PageMeta,
 }
 
+impl Page for ParticipantConversationPage {
+    type Item = ParticipantConversation;
+
+    fn into_list_page(self) -> ListPage<ParticipantConversation> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.conversations,
+        }
+    }
+}
+
 /// Participant conversation details.
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 pub struct ParticipantConversation {
@@ -43,7 +56,7 @@ pub struct ParticipantConversation {
     pub conversation_date_updated: String,
     pub conversation_created_by: String,
     pub conversation_state: State,
-    pub conversation_timers: Timers,
+    pub conversation_timers: ConversationTimers,
     pub links: Links,
 }
 
@@ -77,45 +90,84 @@ struct ListParams {
 impl<'a> ParticipantConversations<'a> {
     /// [Lists Participant Conversations](https://www.twilio.com/docs/conversations/api/participant-conversation-resource#list-all-of-a-participants-conversations)
     ///
-    /// This will eagerly fetch *all* conversations relating to a particular identity or address on the Twilio account.
+    /// This will eagerly fetch *all* conversations relating to a particular identity or address on the Twilio account,
+    /// or the first `limit` of them.
+    ///
+    /// Unlike `Conversations::list` this endpoint doesn't support filtering by state so every state
+    /// is returned. Use `filter_by_state` on the result to keep a single state, bearing in mind the
+    /// `limit` applies before filtering.
     ///
     /// Takes optional parameters:
     /// - `identity` - The identity used for the participant (used for participants using the Conversations SDK).
     /// - `address` - Or the address the participant is communicating on. This typically links directly to `messaging_binding.address` of a Conversation.
+    /// - `limit` - Stop fetching pages once this many conversations are retrieved.
     pub async fn list(
         &self,
         identity: Option<String>,
         address: Option<String>,
+        limit: Option<usize>,
     ) -> Result<Vec<ParticipantConversation>, TwilioError> {
         let params = ListParams { identity, address };
 
-        let mut participant_conversations_page = self
-            .client
-            .send_request::<ParticipantConversationPage, ListParams>(
-                Method::GET,
-                "https://conversations.twilio.com/v1/ParticipantConversations",
-                Some(&params),
-                None,
+        let url = match self.service_sid {
+            Some(service_sid) => format!(
+                "https://conversations.twilio.com/v1/Services/{}/ParticipantConversations",
+                service_sid
+            ),
+            None => String::from("https://conversations.twilio.com/v1/ParticipantConversations"),
+        };
+
+        self.client
+            .collect_pages_with_progress(
+                self.client
+                    .list_pages::<ParticipantConversationPage, ListParams>(url, Some(params)),
+                limit,
+                |_| (),
             )
-            .await?;
-
-        let mut results: Vec<ParticipantConversation> =
-            participant_conversations_page.conversations;
-
-        while (participant_conversations_page.meta.next_page_url).is_some() {
-            participant_conversations_page = self
-                .client
-                .send_request::<ParticipantConversationPage, ()>(
-                    Method::GET,
-                    &participant_conversations_page.meta.next_page_url.unwrap(),
-                    None,
-                    None,
-                )
-                .await?;
-
-            results.append(&mut participant_conversations_page.conversations);
-        }
+            .await
+    }
+}
+
+/// Keeps only the participant conversations in `state`, e.g. the active conversations of a
+/// `ParticipantConversations::list` result.
+pub fn filter_by_state(
+    participant_conversations: Vec<ParticipantConversation>,
+    state: &State,
+) -> Vec<ParticipantConversation> {
+    participant_conversations
+        .into_iter()
+        .filter(|participant_conversation| participant_conversation.conversation_state == *state)
+        .collect()
+}
 
-        Ok(results)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn participant_conversations_filtered_by_state() {
+        let participant_conversations = [
+            ("CH1", State::Active),
+            ("CH2", State::Closed),
+            ("CH3", State::Active),
+            ("CH4", State::Inactive),
+        ]
+        .into_iter()
+        .map(|(sid, state)| ParticipantConversation {
+            conversation_sid: sid.into(),
+            conversation_state: state,
+            ..Default::default()
+        })
+        .collect();
+
+        let active = filter_by_state(participant_conversations, &State::Active);
+
+        assert_eq!(
+            active
+                .iter()
+                .map(|participant_conversation| participant_conversation.conversation_sid.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["CH1", "CH3"]
+        );
     }
 }
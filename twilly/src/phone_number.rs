@@ -0,0 +1,172 @@
+/*!
+
+Contains Twilio phone number related functionality.
+
+*/
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{Client, ListPage, Page, TwilioError};
+
+/// Holds phone number related functions accessible
+/// on the client.
+pub struct PhoneNumbers<'a> {
+    pub client: &'a Client,
+}
+
+impl<'a> PhoneNumbers<'a> {
+    /// Incoming phone number functions, the numbers owned by the account.
+    pub fn incoming(&self) -> IncomingPhoneNumbers<'a> {
+        IncomingPhoneNumbers {
+            client: self.client,
+        }
+    }
+}
+
+/// Represents a page of incoming phone numbers from the Twilio API.
+#[allow(dead_code)]
+#[derive(Deserialize)]
+pub struct IncomingPhoneNumberPage {
+    incoming_phone_numbers: Vec<IncomingPhoneNumber>,
+    next_page_uri: Option<String>,
+}
+
+impl Page for IncomingPhoneNumberPage {
+    type Item = IncomingPhoneNumber;
+
+    fn into_list_page(self) -> ListPage<IncomingPhoneNumber> {
+        ListPage {
+            next_page_url: self
+                .next_page_uri
+                .map(|next_page_uri| format!("https://api.twilio.com{}", next_page_uri)),
+            items: self.incoming_phone_numbers,
+        }
+    }
+}
+
+/// A phone number owned by the account.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IncomingPhoneNumber {
+    pub sid: String,
+    pub account_sid: String,
+    /// The number in E.164 format, e.g. `+14155552671`.
+    pub phone_number: String,
+    pub friendly_name: String,
+    pub capabilities: Capabilities,
+    /// URL Twilio requests when the number receives an SMS.
+    pub sms_url: Option<String>,
+    /// URL Twilio requests when the number receives a call.
+    pub voice_url: Option<String>,
+    pub date_created: String,
+}
+
+/// The channels a phone number can send and receive on.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Capabilities {
+    #[serde(alias = "Voice")]
+    pub voice: bool,
+    #[serde(alias = "SMS")]
+    pub sms: bool,
+    #[serde(alias = "MMS")]
+    pub mms: bool,
+    #[serde(alias = "Fax")]
+    pub fax: bool,
+}
+
+/// Possible filters when listing incoming phone numbers via the Twilio API.
+#[skip_serializing_none]
+#[derive(Serialize)]
+#[serde(rename_all(serialize = "PascalCase"))]
+pub struct ListParams {
+    pub friendly_name: Option<String>,
+    pub phone_number: Option<String>,
+}
+
+pub struct IncomingPhoneNumbers<'a> {
+    pub client: &'a Client,
+}
+
+impl<'a> IncomingPhoneNumbers<'a> {
+    /// [Lists incoming phone numbers](https://www.twilio.com/docs/phone-numbers/api/incomingphonenumber-resource#read-multiple-incomingphonenumber-resources)
+    ///
+    /// This will list the phone numbers owned by the account that match the provided criteria.
+    ///
+    /// Phone numbers will be _eagerly_ paged until all retrieved.
+    ///
+    /// Takes optional parameters:
+    /// - `friendly_name` - Return only numbers matching this friendly name
+    /// - `phone_number` - Return only numbers containing this pattern, e.g. `415`
+    pub async fn list(
+        &self,
+        friendly_name: Option<&str>,
+        phone_number: Option<&str>,
+    ) -> Result<Vec<IncomingPhoneNumber>, TwilioError> {
+        let params = ListParams {
+            friendly_name: friendly_name.map(|friendly_name| friendly_name.to_string()),
+            phone_number: phone_number.map(|phone_number| phone_number.to_string()),
+        };
+
+        self.client
+            .paginate::<IncomingPhoneNumberPage, ListParams>(
+                format!(
+                    "https://api.twilio.com/2010-04-01/Accounts/{}/IncomingPhoneNumbers.json?PageSize=50",
+                    self.client.config.account_sid
+                ),
+                Some(params),
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{serve, test_config, MockResponse};
+    use crate::{Client, Domain};
+
+    #[tokio::test]
+    async fn incoming_phone_numbers_listed_with_filters() {
+        let server = serve([MockResponse::json(r#"{"incoming_phone_numbers":[{"sid":"PN1","account_sid":"AC11111111111111111111111111111111","phone_number":"+14155552671","friendly_name":"Support","capabilities":{"voice":true,"sms":true,"mms":false,"fax":false},"sms_url":"https://example.com/sms","voice_url":null,"date_created":"Mon, 01 Jan 2024 00:00:00 +0000"}],"next_page_uri":null,"page":0,"page_size":50}"#)]).await;
+        let client = Client::new(&test_config()).with_base_url(Domain::Api, &server.url());
+
+        let phone_numbers = client
+            .phone_numbers()
+            .incoming()
+            .list(Some("Support"), Some("415"))
+            .await
+            .unwrap();
+
+        assert_eq!(phone_numbers.len(), 1);
+        assert_eq!(phone_numbers[0].phone_number, "+14155552671");
+        assert_eq!(phone_numbers[0].voice_url, None);
+        let request = server.request();
+        assert!(request.starts_with(
+            "GET /2010-04-01/Accounts/AC11111111111111111111111111111111/IncomingPhoneNumbers.json?PageSize=50&FriendlyName=Support&PhoneNumber=415 "
+        ));
+    }
+
+    #[test]
+    fn phone_number_capabilities_deserialized() {
+        use Capabilities;
+
+        let capabilities: Capabilities =
+            serde_json::from_str(r#"{"voice":true,"sms":false,"mms":true,"fax":false}"#).unwrap();
+        assert_eq!(
+            capabilities,
+            Capabilities {
+                voice: true,
+                sms: false,
+                mms: true,
+                fax: false,
+            }
+        );
+
+        // Older resources name the channels in upper case and may omit fax.
+        let capabilities: Capabilities =
+            serde_json::from_str(r#"{"voice":true,"SMS":true,"MMS":false}"#).unwrap();
+        assert!(capabilities.voice && capabilities.sms && !capabilities.mms && !capabilities.fax);
+    }
+}
@@ -5,12 +5,14 @@ Contains Twilio Serverless Environment related functionality.
 */
 
 pub mod logs;
+pub mod variables;
 
-use crate::{Client, PageMeta, TwilioError};
+use crate::{Client, ListPage, Page, PageMeta, TwilioError};
 use logs::{Log, Logs};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use variables::Variables;
 
 /// Represents a page of Serverless Environments from the Twilio API.
 #[allow(dead_code)]
@@ -20,8 +22,19 @@ pub struct EnvironmentPage {
     meta: PageMeta,
 }
 
+impl Page for EnvironmentPage {
+    type Item = ServerlessEnvironment;
+
+    fn into_list_page(self) -> ListPage<ServerlessEnvironment> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.environments,
+        }
+    }
+}
+
 /// A Serverless Environment resource.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ServerlessEnvironment {
     pub sid: String,
     pub account_sid: String,
@@ -35,6 +48,8 @@ pub struct ServerlessEnvironment {
     pub url: String,
     pub date_created: String,
     pub date_updated: String,
+    #[serde(default)]
+    pub links: Links,
 }
 
 /// Resources _linked_ to a environment.
@@ -47,7 +62,7 @@ pub struct Links {
 
 /// Parameters for creating an Environment.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Default, Serialize)]
 #[serde(rename_all(serialize = "PascalCase"))]
 pub struct CreateParams {
     pub unique_name: String,
@@ -55,6 +70,23 @@ pub struct CreateParams {
     pub domain_suffix: Option<String>,
 }
 
+impl CreateParams {
+    /// Parameters with the required `unique_name`, further options can be chained
+    /// e.g. `CreateParams::new("staging").domain_suffix("stage")`.
+    pub fn new(unique_name: impl Into<String>) -> Self {
+        Self {
+            unique_name: unique_name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// URL-friendly name that forms part of the domain name.
+    pub fn domain_suffix(mut self, domain_suffix: impl Into<String>) -> Self {
+        self.domain_suffix = Some(domain_suffix.into());
+        self
+    }
+}
+
 pub struct Environments<'a, 'b> {
     pub client: &'a Client,
     pub service_sid: &'b str,
@@ -90,36 +122,38 @@ impl<'a, 'b> Environments<'a, 'b> {
     ///
     /// Environments will be _eagerly_ paged until all retrieved.
     pub async fn list(&self) -> Result<Vec<ServerlessEnvironment>, TwilioError> {
-        let mut environments_page = self
-            .client
-            .send_request::<EnvironmentPage, ()>(
-                Method::GET,
-                &format!(
+        self.client
+            .paginate::<EnvironmentPage, ()>(
+                format!(
                     "https://serverless.twilio.com/v1/Services/{}/Environments?PageSize=50",
                     self.service_sid
                 ),
                 None,
-                None,
             )
-            .await?;
-
-        let mut results: Vec<ServerlessEnvironment> = environments_page.environments;
-
-        while (environments_page.meta.next_page_url).is_some() {
-            environments_page = self
-                .client
-                .send_request::<EnvironmentPage, ()>(
-                    Method::GET,
-                    &environments_page.meta.next_page_url.unwrap(),
-                    None,
-                    None,
-                )
-                .await?;
-
-            results.append(&mut environments_page.environments);
-        }
+            .await
+    }
+
+    /// Fetches a single page of Environments for the Service provided to the `service()`
+    /// argument. The first page is fetched when `page_url` is `None`, otherwise the page at
+    /// the `next_page_url` of a previous page.
+    pub async fn page(
+        &self,
+        page_url: Option<&str>,
+    ) -> Result<ListPage<ServerlessEnvironment>, TwilioError> {
+        let first_page_url = format!(
+            "https://serverless.twilio.com/v1/Services/{}/Environments?PageSize=50",
+            self.service_sid
+        );
 
-        Ok(results)
+        self.client
+            .send_request::<EnvironmentPage, ()>(
+                Method::GET,
+                page_url.unwrap_or(&first_page_url),
+                None,
+                None,
+            )
+            .await
+            .map(Page::into_list_page)
     }
 }
 
@@ -137,15 +171,10 @@ impl<'a, 'b> Environment<'a, 'b> {
     /// provided to the `environment()` argument.
     pub async fn get(&self) -> Result<ServerlessEnvironment, TwilioError> {
         self.client
-            .send_request::<ServerlessEnvironment, ()>(
-                Method::GET,
-                &format!(
-                    "https://serverless.twilio.com/v1/Services/{}/Environments/{}",
-                    self.service_sid, self.sid
-                ),
-                None,
-                None,
-            )
+            .get_resource::<ServerlessEnvironment>(&format!(
+                "https://serverless.twilio.com/v1/Services/{}/Environments/{}",
+                self.service_sid, self.sid
+            ))
             .await
     }
 
@@ -187,4 +216,13 @@ impl<'a, 'b> Environment<'a, 'b> {
             environment_sid: self.sid,
         }
     }
+
+    /// Environment Variable functions.
+    pub fn variables(&'a self) -> Variables {
+        Variables {
+            client: self.client,
+            service_sid: self.service_sid,
+            environment_sid: self.sid,
+        }
+    }
 }
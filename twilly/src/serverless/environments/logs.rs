@@ -4,10 +4,11 @@ Contains Twilio Serverless Environment Logs related functionality.
 
 */
 
-use crate::{Client, PageMeta, TwilioError};
-use reqwest::Method;
-use serde::{Deserialize, Serialize};
-use strum_macros::{AsRefStr, Display, EnumIter, EnumString};
+use std::fmt;
+
+use crate::{page_size, Client, ListPage, Page, PageMeta, TwilioError};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use strum_macros::{EnumIter, EnumString};
 
 /// Represents a page of Serverless Environments from the Twilio API.
 #[allow(dead_code)]
@@ -17,8 +18,19 @@ pub struct LogsPage {
     meta: PageMeta,
 }
 
+impl Page for LogsPage {
+    type Item = ServerlessLog;
+
+    fn into_list_page(self) -> ListPage<ServerlessLog> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.logs,
+        }
+    }
+}
+
 /// A Serverless Environment Log resource.
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct ServerlessLog {
     pub sid: String,
     pub account_sid: String,
@@ -34,19 +46,9 @@ pub struct ServerlessLog {
     pub url: String,
 }
 
-#[derive(
-    AsRefStr,
-    Clone,
-    Display,
-    Default,
-    Debug,
-    EnumIter,
-    EnumString,
-    Serialize,
-    Deserialize,
-    PartialEq,
-)]
-#[serde(rename_all = "UPPERCASE")]
+/// Level of a Serverless Log. Levels unknown to this crate are preserved as `Other`
+/// so an unexpected value from Twilio doesn't prevent logs being retrieved.
+#[derive(Clone, Default, Debug, EnumIter, EnumString, PartialEq)]
 pub enum Level {
     #[default]
     #[strum(to_string = "Info")]
@@ -55,6 +57,50 @@ pub enum Level {
     Warn,
     #[strum(to_string = "Error")]
     Error,
+    /// A level not recognised by this crate, as returned by Twilio.
+    #[strum(disabled)]
+    Other(String),
+}
+
+impl AsRef<str> for Level {
+    fn as_ref(&self) -> &str {
+        match self {
+            Level::Info => "Info",
+            Level::Warn => "Warn",
+            Level::Error => "Error",
+            Level::Other(level) => level,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl Serialize for Level {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Level::Info => serializer.serialize_str("INFO"),
+            Level::Warn => serializer.serialize_str("WARN"),
+            Level::Error => serializer.serialize_str("ERROR"),
+            Level::Other(level) => serializer.serialize_str(level),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Level {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let level = String::deserialize(deserializer)?;
+
+        Ok(match level.as_str() {
+            "INFO" => Level::Info,
+            "WARN" => Level::Warn,
+            "ERROR" => Level::Error,
+            _ => Level::Other(level),
+        })
+    }
 }
 
 /// Arguments for listing Serverless Logs
@@ -65,8 +111,14 @@ pub struct ListParams {
     pub function_sid: Option<String>,
     pub start_date: Option<String>,
     pub end_date: Option<String>,
+    /// Number of Logs fetched per request, between 1 and 1000. Defaults to 500.
+    #[serde(skip)]
+    pub page_size: Option<u16>,
 }
 
+// Logs are fetched in larger pages than other resources as Environments produce many.
+const DEFAULT_LOGS_PAGE_SIZE: u16 = 500;
+
 pub struct Logs<'a, 'b> {
     pub client: &'a Client,
     pub service_sid: &'b str,
@@ -81,48 +133,40 @@ impl<'a, 'b> Logs<'a, 'b> {
     ///
     /// Logs will be _eagerly_ paged until all retrieved. If `start_date` is None, this defaults to 1 day in the
     /// past. If `end_date` is None, this defaults to the current datetime.
+    ///
+    /// See `list_with_params` to also set the page size.
     pub async fn list(
         &self,
         function_sid: Option<String>,
         start_date: Option<chrono::DateTime<chrono::Utc>>,
         end_date: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<ServerlessLog>, TwilioError> {
-        let params = ListParams {
+        self.list_with_params(ListParams {
             function_sid,
             start_date: start_date.map(|sd| sd.format("%Y-%m-%dT%H:%M:%SZ").to_string()),
             end_date: end_date.map(|ed| ed.format("%Y-%m-%dT%H:%M:%SZ").to_string()),
-        };
-
-        let mut logs_page = self
-            .client
-            .send_request::<LogsPage, ListParams>(
-                Method::GET,
-                &format!(
-                    "https://serverless.twilio.com/v1/Services/{}/Environments/{}/Logs?PageSize=500",
-                    self.service_sid, self.environment_sid
+            page_size: None,
+        })
+        .await
+    }
+
+    /// Lists Logs as `list` does, taking the filters and page size as `ListParams`. A
+    /// `page_size` outside of 1-1000 is rejected before any request is sent.
+    pub async fn list_with_params(
+        &self,
+        params: ListParams,
+    ) -> Result<Vec<ServerlessLog>, TwilioError> {
+        let page_size = page_size(Some(params.page_size.unwrap_or(DEFAULT_LOGS_PAGE_SIZE)))?;
+
+        self.client
+            .paginate::<LogsPage, ListParams>(
+                format!(
+                    "https://serverless.twilio.com/v1/Services/{}/Environments/{}/Logs?PageSize={}",
+                    self.service_sid, self.environment_sid, page_size
                 ),
-                Some(&params),
-                None,
+                Some(params),
             )
-            .await?;
-
-        let mut results: Vec<ServerlessLog> = logs_page.logs;
-
-        while (logs_page.meta.next_page_url).is_some() {
-            logs_page = self
-                .client
-                .send_request::<LogsPage, ()>(
-                    Method::GET,
-                    &logs_page.meta.next_page_url.unwrap(),
-                    None,
-                    None,
-                )
-                .await?;
-
-            results.append(&mut logs_page.logs);
-        }
-
-        Ok(results)
+            .await
     }
 }
 
@@ -141,15 +185,26 @@ impl<'a, 'b> Log<'a, 'b> {
     /// the `environment()` argument and fetches a Log provided to the `log()` argument.
     pub async fn get(&self) -> Result<ServerlessLog, TwilioError> {
         self.client
-            .send_request::<ServerlessLog, ()>(
-                Method::GET,
-                &format!(
-                    "https://serverless.twilio.com/v1/Services/{}/Environments/{}/Logs/{}",
-                    self.service_sid, self.environment_sid, self.sid
-                ),
-                None,
-                None,
-            )
+            .get_resource::<ServerlessLog>(&format!(
+                "https://serverless.twilio.com/v1/Services/{}/Environments/{}/Logs/{}",
+                self.service_sid, self.environment_sid, self.sid
+            ))
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_level_deserialises_as_other() {
+        let level: Level = serde_json::from_str(r#""DEBUG""#).unwrap();
+        assert_eq!(level, Level::Other(String::from("DEBUG")));
+        assert_eq!(level.to_string(), "DEBUG");
+        assert_eq!(serde_json::to_string(&level).unwrap(), r#""DEBUG""#);
+
+        let level: Level = serde_json::from_str(r#""WARN""#).unwrap();
+        assert_eq!(level, Level::Warn);
+    }
+}
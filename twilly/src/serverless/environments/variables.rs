@@ -0,0 +1,200 @@
+/*!
+
+Contains Twilio Serverless Environment Variable related functionality.
+
+*/
+
+use std::fmt;
+
+use crate::{Client, ListPage, Page, PageMeta, TwilioError};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Represents a page of Environment Variables from the Twilio API.
+#[allow(dead_code)]
+#[derive(Deserialize)]
+pub struct VariablePage {
+    variables: Vec<Variable>,
+    meta: PageMeta,
+}
+
+impl Page for VariablePage {
+    type Item = Variable;
+
+    fn into_list_page(self) -> ListPage<Variable> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.variables,
+        }
+    }
+}
+
+/// A Serverless Environment Variable resource.
+///
+/// The `value` is often a secret so is redacted from the `Debug` output.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Variable {
+    pub sid: String,
+    pub account_sid: String,
+    pub service_sid: String,
+    pub environment_sid: String,
+    pub key: String,
+    pub value: String,
+    pub url: String,
+    pub date_created: String,
+    pub date_updated: String,
+}
+
+impl fmt::Debug for Variable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Variable")
+            .field("sid", &self.sid)
+            .field("account_sid", &self.account_sid)
+            .field("service_sid", &self.service_sid)
+            .field("environment_sid", &self.environment_sid)
+            .field("key", &self.key)
+            .field("value", &"[REDACTED]")
+            .field("url", &self.url)
+            .field("date_created", &self.date_created)
+            .field("date_updated", &self.date_updated)
+            .finish()
+    }
+}
+
+/// Parameters for creating a Variable
+#[derive(Serialize)]
+#[serde(rename_all(serialize = "PascalCase"))]
+struct CreateParams<'a> {
+    key: &'a str,
+    value: &'a str,
+}
+
+/// Parameters for updating a Variable. Options can be chained from the default,
+/// e.g. `UpdateParams::default().value("new")`.
+#[skip_serializing_none]
+#[derive(Default, Serialize)]
+#[serde(rename_all(serialize = "PascalCase"))]
+pub struct UpdateParams {
+    pub key: Option<String>,
+    pub value: Option<String>,
+}
+
+impl UpdateParams {
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+}
+
+pub struct Variables<'a, 'b> {
+    pub client: &'a Client,
+    pub service_sid: &'b str,
+    pub environment_sid: &'b str,
+}
+
+impl<'a, 'b> Variables<'a, 'b> {
+    /// [Creates a Variable](https://www.twilio.com/docs/serverless/api/resource/variable#create-a-variable-resource)
+    ///
+    /// Creates a Variable with `key` and `value` in the Environment provided to the
+    /// `environment()` argument. Deployed functions see the change on their next invocation.
+    pub async fn create(&self, key: &str, value: &str) -> Result<Variable, TwilioError> {
+        self.client
+            .send_request::<Variable, CreateParams>(
+                Method::POST,
+                &self.variables_url(),
+                Some(&CreateParams { key, value }),
+                None,
+            )
+            .await
+    }
+
+    /// [Lists Variables](https://www.twilio.com/docs/serverless/api/resource/variable#read-multiple-variable-resources)
+    ///
+    /// Lists Variables of the Environment provided to the `environment()` argument.
+    ///
+    /// Variables will be _eagerly_ paged until all retrieved.
+    pub async fn list(&self) -> Result<Vec<Variable>, TwilioError> {
+        self.client
+            .collect_pages(self.client.list_pages::<VariablePage, ()>(
+                format!("{}?PageSize=50", self.variables_url()),
+                None,
+            ))
+            .await
+    }
+
+    /// [Gets a Variable](https://www.twilio.com/docs/serverless/api/resource/variable#fetch-a-variable-resource)
+    ///
+    /// Takes in the `sid` (`ZV...`) of the Variable to fetch.
+    pub async fn get(&self, sid: &str) -> Result<Variable, TwilioError> {
+        self.client
+            .get_resource::<Variable>(&format!("{}/{}", self.variables_url(), sid))
+            .await
+    }
+
+    /// [Updates a Variable](https://www.twilio.com/docs/serverless/api/resource/variable#update-a-variable-resource)
+    ///
+    /// Takes in the `sid` (`ZV...`) of the Variable and updates it with the provided properties.
+    pub async fn update(&self, sid: &str, params: UpdateParams) -> Result<Variable, TwilioError> {
+        self.client
+            .send_request::<Variable, UpdateParams>(
+                Method::POST,
+                &format!("{}/{}", self.variables_url(), sid),
+                Some(&params),
+                None,
+            )
+            .await
+    }
+
+    /// [Deletes a Variable](https://www.twilio.com/docs/serverless/api/resource/variable#delete-a-variable-resource)
+    ///
+    /// Takes in the `sid` (`ZV...`) of the Variable and **deletes** it.
+    pub async fn delete(&self, sid: &str) -> Result<(), TwilioError> {
+        self.client
+            .send_request_and_ignore_response::<()>(
+                Method::DELETE,
+                &format!("{}/{}", self.variables_url(), sid),
+                None,
+                None,
+            )
+            .await
+    }
+
+    fn variables_url(&self) -> String {
+        format!(
+            "https://serverless.twilio.com/v1/Services/{}/Environments/{}/Variables",
+            self.service_sid, self.environment_sid
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variable_value_redacted_from_debug() {
+        let variable: Variable = serde_json::from_value(serde_json::json!({
+            "sid": "ZV1",
+            "account_sid": "AC1",
+            "service_sid": "ZS1",
+            "environment_sid": "ZE1",
+            "key": "API_KEY",
+            "value": "secret",
+            "url": "",
+            "date_created": "",
+            "date_updated": ""
+        }))
+        .unwrap();
+
+        let debug = format!("{:?}", variable);
+
+        assert!(debug.contains("API_KEY"));
+        assert!(!debug.contains("secret"));
+    }
+}
@@ -4,7 +4,7 @@ Contains Twilio Serverless related functionality.
 
 */
 
-use crate::{Client, PageMeta, TwilioError};
+use crate::{Client, ListPage, Page, PageMeta, TwilioError};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -19,8 +19,19 @@ pub struct ServerlessServicePage {
     meta: PageMeta,
 }
 
+impl Page for ServerlessServicePage {
+    type Item = ServerlessService;
+
+    fn into_list_page(self) -> ListPage<ServerlessService> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.services,
+        }
+    }
+}
+
 /// A Serverless Service resource.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ServerlessService {
     pub sid: String,
     pub account_sid: String,
@@ -50,7 +61,7 @@ pub struct Links {
 /// Parameters for creating or updating a Serverlesss Service. See `ServerlessService` for
 /// details on individual parameters.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Default, Serialize)]
 #[serde(rename_all(serialize = "PascalCase"))]
 pub struct CreateOrUpdateParams {
     pub unique_name: String,
@@ -59,6 +70,30 @@ pub struct CreateOrUpdateParams {
     pub ui_editable: Option<bool>,
 }
 
+impl CreateOrUpdateParams {
+    /// Parameters with the required `unique_name` and `friendly_name`, further options
+    /// can be chained e.g. `CreateOrUpdateParams::new("example", "Example").ui_editable(true)`.
+    pub fn new(unique_name: impl Into<String>, friendly_name: impl Into<String>) -> Self {
+        Self {
+            unique_name: unique_name.into(),
+            friendly_name: friendly_name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Whether to inject the account credentials into the Service's Functions.
+    pub fn include_credentials(mut self, include_credentials: bool) -> Self {
+        self.include_credentials = Some(include_credentials);
+        self
+    }
+
+    /// Whether the Service's Functions and Assets can be edited in the console.
+    pub fn ui_editable(mut self, ui_editable: bool) -> Self {
+        self.ui_editable = Some(ui_editable);
+        self
+    }
+}
+
 pub struct Services<'a> {
     pub client: &'a Client,
 }
@@ -87,33 +122,29 @@ impl<'a> Services<'a> {
     ///
     /// Services will be _eagerly_ paged until all retrieved.
     pub async fn list(&self) -> Result<Vec<ServerlessService>, TwilioError> {
-        let mut services_page = self
-            .client
+        self.client
+            .paginate::<ServerlessServicePage, ()>(
+                String::from("https://serverless.twilio.com/v1/Services?PageSize=20"),
+                None,
+            )
+            .await
+    }
+
+    /// Fetches a single page of Serverless Services. The first page is fetched when
+    /// `page_url` is `None`, otherwise the page at the `next_page_url` of a previous page.
+    pub async fn page(
+        &self,
+        page_url: Option<&str>,
+    ) -> Result<ListPage<ServerlessService>, TwilioError> {
+        self.client
             .send_request::<ServerlessServicePage, ()>(
                 Method::GET,
-                "https://serverless.twilio.com/v1/Services?PageSize=20",
+                page_url.unwrap_or("https://serverless.twilio.com/v1/Services?PageSize=20"),
                 None,
                 None,
             )
-            .await?;
-
-        let mut results: Vec<ServerlessService> = services_page.services;
-
-        while (services_page.meta.next_page_url).is_some() {
-            services_page = self
-                .client
-                .send_request::<ServerlessServicePage, ()>(
-                    Method::GET,
-                    &services_page.meta.next_page_url.unwrap(),
-                    None,
-                    None,
-                )
-                .await?;
-
-            results.append(&mut services_page.services);
-        }
-
-        Ok(results)
+            .await
+            .map(Page::into_list_page)
     }
 }
 
@@ -128,12 +159,10 @@ impl<'a, 'b> Service<'a, 'b> {
     /// Fetches the Serverless Service provided to the `Service()`.
     pub async fn get(&self) -> Result<ServerlessService, TwilioError> {
         self.client
-            .send_request::<ServerlessService, ()>(
-                Method::GET,
-                &format!("https://serverless.twilio.com/v1/Services/{}", self.sid),
-                None,
-                None,
-            )
+            .get_resource::<ServerlessService>(&format!(
+                "https://serverless.twilio.com/v1/Services/{}",
+                self.sid
+            ))
             .await
     }
 
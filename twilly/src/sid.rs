@@ -0,0 +1,195 @@
+/*!
+
+Contains typed Twilio SIDs, validated against the prefix of the resource they identify.
+
+*/
+
+use std::{fmt, hash::Hash, marker::PhantomData, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{util::is_valid_sid, ErrorKind, TwilioError};
+
+/// Validates `sid` identifies the resource with `prefix`, e.g. `CH` for Conversations.
+/// A SID is the two character prefix followed by 32 hexadecimal characters.
+pub fn validate_sid(prefix: &str, sid: &str) -> Result<(), TwilioError> {
+    match is_valid_sid(prefix, sid) {
+        true => Ok(()),
+        false => Err(TwilioError {
+            kind: ErrorKind::ValidationError(format!(
+                "SID '{}' must start with {} followed by 32 hexadecimal characters",
+                sid, prefix
+            )),
+        }),
+    }
+}
+
+/// A kind of Twilio resource, identified by the prefix of its SIDs.
+pub trait SidPrefix: Clone + fmt::Debug + PartialEq + Eq + Hash {
+    /// The two character prefix of the resource's SIDs, e.g. `CH`.
+    const PREFIX: &'static str;
+}
+
+/// A SID checked to identify the resource `P` on creation, e.g. `ConversationSid`.
+/// Parsed via `Sid::new` or `str::parse` and (de)serialized as a plain string.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Sid<P: SidPrefix> {
+    sid: String,
+    prefix: PhantomData<P>,
+}
+
+impl<P: SidPrefix> Sid<P> {
+    /// Validates `sid` against the prefix of `P`, see `validate_sid`.
+    pub fn new(sid: impl Into<String>) -> Result<Self, TwilioError> {
+        let sid = sid.into();
+        validate_sid(P::PREFIX, &sid)?;
+
+        Ok(Sid {
+            sid,
+            prefix: PhantomData,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.sid
+    }
+
+    pub fn into_string(self) -> String {
+        self.sid
+    }
+}
+
+impl<P: SidPrefix> FromStr for Sid<P> {
+    type Err = TwilioError;
+
+    fn from_str(sid: &str) -> Result<Self, Self::Err> {
+        Sid::new(sid)
+    }
+}
+
+impl<P: SidPrefix> AsRef<str> for Sid<P> {
+    fn as_ref(&self) -> &str {
+        &self.sid
+    }
+}
+
+impl<P: SidPrefix> fmt::Display for Sid<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.sid)
+    }
+}
+
+impl<P: SidPrefix> Serialize for Sid<P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.sid)
+    }
+}
+
+impl<'de, P: SidPrefix> Deserialize<'de> for Sid<P> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let sid = String::deserialize(deserializer)?;
+        Sid::new(sid).map_err(serde::de::Error::custom)
+    }
+}
+
+// Declares a `SidPrefix` marker for each resource along with an alias of `Sid` for it.
+macro_rules! sid_types {
+    ($($resource:literal, $marker:ident, $alias:ident, $prefix:literal;)*) => {
+        $(
+            #[doc = concat!("Marks SIDs of ", $resource, ", starting with `", $prefix, "`.")]
+            #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+            pub struct $marker;
+
+            impl SidPrefix for $marker {
+                const PREFIX: &'static str = $prefix;
+            }
+
+            #[doc = concat!("SID of ", $resource, ", e.g. `", $prefix, "...`.")]
+            pub type $alias = Sid<$marker>;
+        )*
+    };
+}
+
+sid_types! {
+    "an Account", AccountPrefix, AccountSid, "AC";
+    "an API Key", ApiKeyPrefix, ApiKeySid, "SK";
+    "a Conversation", ConversationPrefix, ConversationSid, "CH";
+    "a Conversation Participant", ParticipantPrefix, ParticipantSid, "MB";
+    "a Conversation Message", ConversationMessagePrefix, ConversationMessageSid, "IM";
+    "a Conversation or Sync Service", ServicePrefix, ServiceSid, "IS";
+    "a Messaging Service", MessagingServicePrefix, MessagingServiceSid, "MG";
+    "a Message", MessagePrefix, MessageSid, "SM";
+    "an incoming phone number", PhoneNumberPrefix, PhoneNumberSid, "PN";
+    "a Sync Document", SyncDocumentPrefix, SyncDocumentSid, "ET";
+    "a Sync List", SyncListPrefix, SyncListSid, "ES";
+    "a Sync Map", SyncMapPrefix, SyncMapSid, "MP";
+    "a Serverless Service", ServerlessServicePrefix, ServerlessServiceSid, "ZS";
+    "a Serverless Environment", ServerlessEnvironmentPrefix, ServerlessEnvironmentSid, "ZE";
+    "a Serverless Function", ServerlessFunctionPrefix, ServerlessFunctionSid, "ZH";
+    "a Serverless Log", ServerlessLogPrefix, ServerlessLogSid, "NO";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn known_prefixes_accepted() {
+        for prefix in [
+            AccountPrefix::PREFIX,
+            ApiKeyPrefix::PREFIX,
+            ConversationPrefix::PREFIX,
+            ParticipantPrefix::PREFIX,
+            ConversationMessagePrefix::PREFIX,
+            ServicePrefix::PREFIX,
+            MessagingServicePrefix::PREFIX,
+            MessagePrefix::PREFIX,
+            PhoneNumberPrefix::PREFIX,
+            SyncDocumentPrefix::PREFIX,
+            SyncListPrefix::PREFIX,
+            SyncMapPrefix::PREFIX,
+            ServerlessServicePrefix::PREFIX,
+            ServerlessEnvironmentPrefix::PREFIX,
+            ServerlessFunctionPrefix::PREFIX,
+            ServerlessLogPrefix::PREFIX,
+        ] {
+            assert!(
+                validate_sid(prefix, &format!("{}{}", prefix, ID)).is_ok(),
+                "{}",
+                prefix
+            );
+            assert!(
+                validate_sid(prefix, &format!("XX{}", ID)).is_err(),
+                "{}",
+                prefix
+            );
+            assert!(
+                validate_sid(prefix, &format!("{}{}", prefix, &ID[1..])).is_err(),
+                "{}",
+                prefix
+            );
+        }
+    }
+
+    #[test]
+    fn typed_sid_checks_prefix() {
+        let conversation_sid: ConversationSid = format!("CH{}", ID).parse().unwrap();
+        assert_eq!(conversation_sid.as_str(), format!("CH{}", ID));
+
+        assert!(AccountSid::new(format!("CH{}", ID)).is_err());
+        assert!(SyncMapSid::new("MP1").is_err());
+    }
+
+    #[test]
+    fn typed_sid_deserialization_validated() {
+        let sid: SyncDocumentSid = serde_json::from_str(&format!("\"ET{}\"", ID)).unwrap();
+        assert_eq!(
+            serde_json::to_string(&sid).unwrap(),
+            format!("\"ET{}\"", ID)
+        );
+
+        assert!(serde_json::from_str::<SyncDocumentSid>(&format!("\"ES{}\"", ID)).is_err());
+    }
+}
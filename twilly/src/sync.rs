@@ -9,8 +9,13 @@ pub mod lists;
 pub mod mapitems;
 pub mod maps;
 pub mod services;
+pub mod streams;
 
-use crate::Client;
+use chrono::{DateTime, Utc};
+
+use reqwest::header::HeaderMap;
+
+use crate::{Client, ErrorKind, TwilioError};
 
 use self::services::{Service, Services};
 
@@ -38,3 +43,37 @@ impl<'a> Sync<'a> {
         }
     }
 }
+
+// Whether `date_created` falls strictly between the bounds given. A `date_created` that
+// can't be parsed always matches, as with `Conversations::list_updated_after`, so nothing
+// is silently dropped from the result.
+fn created_between(
+    date_created: &str,
+    created_after: Option<DateTime<Utc>>,
+    created_before: Option<DateTime<Utc>>,
+) -> bool {
+    let Ok(date_created) = DateTime::parse_from_rfc3339(date_created) else {
+        return true;
+    };
+
+    created_after.map_or(true, |created_after| date_created > created_after)
+        && created_before.map_or(true, |created_before| date_created < created_before)
+}
+
+// Headers carrying `if_match` as the `If-Match` precondition, if provided. A revision that
+// isn't a valid header value is rejected before anything is sent.
+fn if_match_headers(if_match: Option<&str>) -> Result<HeaderMap, TwilioError> {
+    let mut headers = HeaderMap::new();
+
+    if let Some(if_match) = if_match {
+        let value = if_match.parse().map_err(|_| TwilioError {
+            kind: ErrorKind::ValidationError(format!(
+                "If-Match revision '{}' is not a valid header value",
+                if_match
+            )),
+        })?;
+        headers.append("If-Match", value);
+    }
+
+    Ok(headers)
+}
@@ -4,8 +4,9 @@ Contains Twilio Sync Document related functionality.
 
 */
 
-use crate::{Client, PageMeta, TwilioError};
-use reqwest::{header::HeaderMap, Method};
+use crate::{util::validate_json_object, Client, ListPage, Page, PageMeta, TwilioError};
+use futures::Stream;
+use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
@@ -18,8 +19,19 @@ pub struct DocumentPage {
     meta: PageMeta,
 }
 
+impl Page for DocumentPage {
+    type Item = SyncDocument;
+
+    fn into_list_page(self) -> ListPage<SyncDocument> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.documents,
+        }
+    }
+}
+
 /// A Sync Document resource.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SyncDocument {
     pub sid: String,
     pub unique_name: String,
@@ -71,6 +83,8 @@ pub struct UpdateParams<'a, T>
 where
     T: ?Sized + Serialize,
 {
+    /// The `revision` the Document is expected to be at. The update is rejected with
+    /// a `412` if the Document has since been modified.
     pub if_match: Option<String>,
     /// Any value that can be represented as JSON
     pub data: &'a T,
@@ -84,7 +98,8 @@ where
 #[derive(Serialize)]
 #[serde(rename_all(serialize = "PascalCase"))]
 struct UpdateParamsWithJson {
-    #[serde(rename(serialize = "If-Match"))]
+    /// Sent as the `If-Match` header rather than in the body.
+    #[serde(skip)]
     if_match: Option<String>,
     /// Any value that can be represented as JSON
     data: String,
@@ -111,6 +126,7 @@ impl<'a, 'b> Documents<'a, 'b> {
                 .expect("Unable to convert provided data value to a JSON string"),
             ttl: params.ttl,
         };
+        validate_json_object("Data", &params.data)?;
 
         self.client
             .send_request::<SyncDocument, CreateParamsWithJson>(
@@ -129,38 +145,47 @@ impl<'a, 'b> Documents<'a, 'b> {
     ///
     /// Lists Sync Documents in the Sync Service provided to the `service()`.
     ///
-    /// Documents will be _eagerly_ paged until all retrieved.
+    /// Documents will be _eagerly_ paged until all retrieved. See `list_paged` to page lazily.
     pub async fn list(&self) -> Result<Vec<SyncDocument>, TwilioError> {
-        let mut documents_page = self
-            .client
-            .send_request::<DocumentPage, ()>(
-                Method::GET,
-                &format!(
-                    "https://sync.twilio.com/v1/Services/{}/Documents?PageSize=50",
-                    self.service_sid
-                ),
-                None,
-                None,
+        self.client.collect_pages(self.list_paged()).await
+    }
+
+    /// Lists Documents created after `date_created_after` and before `date_created_before`,
+    /// e.g. to find stale Documents to expire. Either bound can be omitted.
+    ///
+    /// Twilio doesn't support filtering Sync Documents by creation time so all Documents are
+    /// eagerly fetched and filtered on `date_created`. Documents with a `date_created` that
+    /// can't be parsed are included, the same as
+    /// `Conversations::list_updated_after`. Check `date_created` before acting on them.
+    pub async fn list_by_date_created(
+        &self,
+        date_created_after: Option<chrono::DateTime<chrono::Utc>>,
+        date_created_before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<SyncDocument>, TwilioError> {
+        let mut documents = self.list().await?;
+
+        documents.retain(|document| {
+            super::created_between(
+                &document.date_created,
+                date_created_after,
+                date_created_before,
             )
-            .await?;
-
-        let mut results: Vec<SyncDocument> = documents_page.documents;
-
-        while (documents_page.meta.next_page_url).is_some() {
-            documents_page = self
-                .client
-                .send_request::<DocumentPage, ()>(
-                    Method::GET,
-                    &documents_page.meta.next_page_url.unwrap(),
-                    None,
-                    None,
-                )
-                .await?;
-
-            results.append(&mut documents_page.documents);
-        }
+        });
+
+        Ok(documents)
+    }
 
-        Ok(results)
+    /// Lists documents a page at a time, see [`ListPage`].
+    pub fn list_paged(
+        &self,
+    ) -> impl Stream<Item = Result<ListPage<SyncDocument>, TwilioError>> + '_ {
+        self.client.list_pages::<DocumentPage, ()>(
+            format!(
+                "https://sync.twilio.com/v1/Services/{}/Documents?PageSize=50",
+                self.service_sid
+            ),
+            None,
+        )
     }
 }
 
@@ -178,15 +203,10 @@ impl<'a, 'b> Document<'a, 'b> {
     /// provided to the `document()` argument.
     pub async fn get(&self) -> Result<SyncDocument, TwilioError> {
         self.client
-            .send_request::<SyncDocument, ()>(
-                Method::GET,
-                &format!(
-                    "https://sync.twilio.com/v1/Services/{}/Documents/{}",
-                    self.service_sid, self.sid
-                ),
-                None,
-                None,
-            )
+            .get_resource::<SyncDocument>(&format!(
+                "https://sync.twilio.com/v1/Services/{}/Documents/{}",
+                self.service_sid, self.sid
+            ))
             .await
     }
 
@@ -194,6 +214,9 @@ impl<'a, 'b> Document<'a, 'b> {
     ///
     /// Targets the Sync Service provided to the `service()` argument and updates the Document
     /// provided to the `document()` argument.
+    ///
+    /// If `if_match` is provided and no longer matches the Document's revision the update is
+    /// rejected. This can be checked with `TwilioError::is_precondition_failed`.
     pub async fn update<T>(&self, params: UpdateParams<'_, T>) -> Result<SyncDocument, TwilioError>
     where
         T: ?Sized + Serialize,
@@ -206,12 +229,9 @@ impl<'a, 'b> Document<'a, 'b> {
                 .expect("Unable to convert provided data value to a JSON string"),
             ttl: params.ttl,
         };
+        validate_json_object("Data", &params.data)?;
 
-        let mut headers = HeaderMap::new();
-
-        if let Some(if_match) = params.if_match.clone() {
-            headers.append("If-Match", if_match.parse().unwrap());
-        }
+        let headers = super::if_match_headers(params.if_match.as_deref())?;
 
         self.client
             .send_request::<SyncDocument, UpdateParamsWithJson>(
@@ -226,7 +246,7 @@ impl<'a, 'b> Document<'a, 'b> {
             .await
     }
 
-    /// [Deletes a Sync Service](https://www.twilio.com/docs/sync/api/service#delete-a-service-resourcee)
+    /// [Deletes a Sync Document](https://www.twilio.com/docs/sync/api/document-resource#delete-a-document-resource)
     ///
     /// Targets the Sync Service provided to the `service()` argument and deletes the Document
     /// provided to the `document()` argument.
@@ -244,3 +264,98 @@ impl<'a, 'b> Document<'a, 'b> {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{serve, test_config, MockResponse};
+    use crate::{Client, Domain, ErrorKind};
+
+    #[tokio::test]
+    async fn document_created_then_deleted() {
+        let server = serve([MockResponse::json(r#"{"sid":"ET1","unique_name":"settings","account_sid":"AC1","service_sid":"IS1","url":"","data":{"theme":"dark"},"date_created":"","date_updated":"","date_expires":null,"created_by":"system","links":{"permissions":""},"revision":"0"}"#).status(201), MockResponse::new(204)]).await;
+        let client = Client::new(&test_config()).with_base_url(Domain::Sync, &server.url());
+        let sync = client.sync();
+        let service = sync.service("IS1");
+
+        let document = service
+            .documents()
+            .create(CreateParams {
+                unique_name: Some(String::from("settings")),
+                data: &serde_json::json!({ "theme": "dark" }),
+                ttl: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(document.data, serde_json::json!({ "theme": "dark" }));
+
+        service.document(&document.sid).delete().await.unwrap();
+        assert_eq!(client.stats().attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn documents_filtered_by_date_created() {
+        let server = serve([MockResponse::json(r#"{"documents":[{"sid":"ET1","unique_name":"et1","account_sid":"AC1","service_sid":"IS1","url":"","data":{},"date_created":"2024-01-01T00:00:00Z","date_updated":"2024-01-01T00:00:00Z","date_expires":null,"created_by":"system","links":{"permissions":""},"revision":"0"},{"sid":"ET2","unique_name":"et2","account_sid":"AC1","service_sid":"IS1","url":"","data":{},"date_created":"2024-09-01T00:00:00Z","date_updated":"2024-09-01T00:00:00Z","date_expires":null,"created_by":"system","links":{"permissions":""},"revision":"0"},{"sid":"ET3","unique_name":"et3","account_sid":"AC1","service_sid":"IS1","url":"","data":{},"date_created":"","date_updated":"","date_expires":null,"created_by":"system","links":{"permissions":""},"revision":"0"}],"meta":{"page":0,"page_size":50,"first_page_url":"","next_page_url":null,"key":"documents"}}"#)]).await;
+        let client = Client::new(&test_config()).with_base_url(Domain::Sync, &server.url());
+
+        let documents = client
+            .sync()
+            .service("IS1")
+            .documents()
+            .list_by_date_created(
+                None,
+                Some(
+                    chrono::DateTime::parse_from_rfc3339("2024-06-01T00:00:00Z")
+                        .unwrap()
+                        .with_timezone(&chrono::Utc),
+                ),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            documents
+                .iter()
+                .map(|document| document.sid.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["ET1", "ET3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn document_delete_targets_sync_document() {
+        let server = serve([MockResponse::new(204)]).await;
+        let client = Client::new(&test_config()).with_base_url(Domain::Sync, &server.url());
+
+        client
+            .sync()
+            .service("IS1")
+            .document("ET1")
+            .delete()
+            .await
+            .unwrap();
+
+        let request = server.request();
+        assert!(request.starts_with("DELETE /v1/Services/IS1/Documents/ET1 "));
+    }
+
+    #[tokio::test]
+    async fn invalid_update_revision_rejected_before_sending() {
+        let client = Client::new(&test_config());
+
+        let error = client
+            .sync()
+            .service("IS1")
+            .document("ET1")
+            .update(UpdateParams {
+                if_match: Some(String::from("7\n")),
+                data: &serde_json::json!({"count": 1}),
+                ttl: None,
+            })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.kind, ErrorKind::ValidationError(_)));
+        assert_eq!(client.stats().attempts, 0);
+    }
+}
@@ -4,8 +4,12 @@ Contains Twilio Sync List Item related functionality.
 
 */
 
-use crate::{Client, PageMeta, TwilioError};
-use reqwest::{header::HeaderMap, Method};
+use crate::{
+    idempotency_headers, page_size, util::validate_json_object, Client, ListPage, ListProgress,
+    Page, PageMeta, TwilioError, MAX_PAGE_SIZE,
+};
+use futures::{future::Either, stream, Stream, StreamExt, TryStreamExt};
+use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
@@ -18,8 +22,19 @@ pub struct ListItemPage {
     meta: PageMeta,
 }
 
+impl Page for ListItemPage {
+    type Item = SyncListItem;
+
+    fn into_list_page(self) -> ListPage<SyncListItem> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.items,
+        }
+    }
+}
+
 /// A Sync List Item resource.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SyncListItem {
     pub index: u32,
     pub account_sid: String,
@@ -46,6 +61,8 @@ where
     pub ttl: Option<u16>,
     /// How long the *parent* List resource should exist before deletion (in seconds).
     pub collection_ttl: Option<u16>,
+    /// Sent as the `I-Twilio-Idempotency-Token` header, see `IDEMPOTENCY_TOKEN_HEADER`.
+    pub idempotency_token: Option<String>,
 }
 
 /// Parameters for creating a Sync List with
@@ -76,7 +93,7 @@ pub enum Bounds {
 
 /// Arguments for listing Sync Map Items
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Default, Serialize)]
 #[serde(rename_all(serialize = "PascalCase"))]
 pub struct ListParams {
     pub order: Option<Order>,
@@ -84,6 +101,13 @@ pub struct ListParams {
     pub from: Option<String>,
     /// Whether to include the Map Item described by the `from` parameter. Defaults to inclusive.
     pub bounds: Option<Bounds>,
+    /// Number of items fetched per request, between 1 and 1000. Defaults to 50. Larger
+    /// pages need fewer requests to list every item.
+    #[serde(skip)]
+    pub page_size: Option<u16>,
+    /// Most items to return. Paging stops once reached rather than fetching every item.
+    #[serde(skip)]
+    pub limit: Option<usize>,
 }
 
 /// Parameters for updating a Sync Map List
@@ -106,7 +130,8 @@ where
 #[derive(Serialize)]
 #[serde(rename_all(serialize = "PascalCase"))]
 struct UpdateParamsWithJson {
-    #[serde(rename(serialize = "If-Match"))]
+    /// Sent as the `If-Match` header rather than in the body.
+    #[serde(skip)]
     if_match: Option<String>,
     data: String,
     /// How long the List Item should exist before deletion (in seconds).
@@ -116,6 +141,13 @@ struct UpdateParamsWithJson {
     collection_ttl: Option<u16>,
 }
 
+/// Parameters for refreshing the TTL of a Sync List Item, leaving its data unchanged.
+#[derive(Serialize)]
+#[serde(rename_all(serialize = "PascalCase"))]
+struct RefreshTtlParams {
+    ttl: u16,
+}
+
 pub struct ListItems<'a, 'b> {
     pub client: &'a Client,
     pub service_sid: &'b str,
@@ -132,12 +164,14 @@ impl<'a, 'b> ListItems<'a, 'b> {
     {
         // Create a new struct with the provided data parameter converted to a
         // JSON string as required by Twilio.
+        let headers = idempotency_headers(params.idempotency_token.as_deref())?;
         let params = CreateParamsWithJson {
             data: serde_json::to_string(params.data)
                 .expect("Unable to convert provided data value to a JSON string"),
             ttl: params.ttl,
             collection_ttl: params.collection_ttl,
         };
+        validate_json_object("Data", &params.data)?;
 
         self.client
             .send_request::<SyncListItem, CreateParamsWithJson>(
@@ -147,7 +181,7 @@ impl<'a, 'b> ListItems<'a, 'b> {
                     self.service_sid, self.list_sid
                 ),
                 Some(&params),
-                None,
+                headers,
             )
             .await
     }
@@ -159,37 +193,109 @@ impl<'a, 'b> ListItems<'a, 'b> {
     /// Targets the Sync Service provided to the `service()` argument, the List provided to the `list()`
     /// argument and lists all List items.
     ///
-    /// List items will be _eagerly_ paged until all retrieved.
+    /// List items will be _eagerly_ paged until all retrieved, or `limit` is reached. See
+    /// `list_paged` to page lazily.
     pub async fn list(&self, params: ListParams) -> Result<Vec<SyncListItem>, TwilioError> {
-        let mut list_items_page = self
-            .client
-            .send_request::<ListItemPage, ListParams>(
-                Method::GET,
-                &format!(
-                    "https://sync.twilio.com/v1/Services/{}/Lists/{}/Items?PageSize=50",
-                    self.service_sid, self.list_sid
+        let limit = params.limit;
+        self.client
+            .collect_pages_with_progress(self.list_paged(params), limit, |_| ())
+            .await
+    }
+
+    /// Lists List items as `list` does, calling `on_page` with the pages and items fetched so
+    /// far after each page. Useful for reporting progress while paging through large lists.
+    pub async fn list_with_progress(
+        &self,
+        params: ListParams,
+        on_page: impl FnMut(ListProgress),
+    ) -> Result<Vec<SyncListItem>, TwilioError> {
+        let limit = params.limit;
+        self.client
+            .collect_pages_with_progress(self.list_paged(params), limit, on_page)
+            .await
+    }
+
+    /// Lists list items a page at a time, see [`ListPage`].
+    pub fn list_paged(
+        &self,
+        params: ListParams,
+    ) -> impl Stream<Item = Result<ListPage<SyncListItem>, TwilioError>> + '_ {
+        match page_size(params.page_size) {
+            Ok(page_size) => Either::Left(self.client.list_pages::<ListItemPage, ListParams>(
+                format!(
+                    "https://sync.twilio.com/v1/Services/{}/Lists/{}/Items?PageSize={}",
+                    self.service_sid, self.list_sid, page_size
                 ),
-                Some(&params),
-                None,
-            )
-            .await?;
-
-        let mut results: Vec<SyncListItem> = list_items_page.items;
-
-        while (list_items_page.meta.next_page_url).is_some() {
-            list_items_page = self
-                .client
-                .send_request::<ListItemPage, ListParams>(
-                    Method::GET,
-                    &list_items_page.meta.next_page_url.unwrap(),
-                    None,
-                    None,
-                )
-                .await?;
-
-            results.append(&mut list_items_page.items);
+                Some(params),
+            )),
+            Err(error) => Either::Right(stream::once(async move { Err(error) })),
+        }
+    }
+
+    /// Fetches the List Items with an index between `from` and `to`, inclusive, in ascending
+    /// order. Pages are requested until an item beyond `to` is reached so only the range is
+    /// fetched. Indexes of deleted items are skipped, so fewer items than the width of the
+    /// range may be returned. A range where `from` is greater than `to` is empty and sends no
+    /// request.
+    pub async fn get_range(&self, from: u32, to: u32) -> Result<Vec<SyncListItem>, TwilioError> {
+        if from > to {
+            return Ok(Vec::new());
         }
 
+        let range_size = (to - from).saturating_add(1);
+        let pages = self.list_paged(ListParams {
+            order: Some(Order::Asc),
+            from: Some(from.to_string()),
+            bounds: Some(Bounds::Inclusive),
+            page_size: Some(range_size.min(u32::from(MAX_PAGE_SIZE)) as u16),
+            limit: None,
+        });
+        let mut pages = std::pin::pin!(pages);
+
+        let mut items = Vec::new();
+        while let Some(page) = pages.try_next().await? {
+            let reached_end = page.items.last().map_or(true, |item| item.index >= to);
+            items.extend(page.items.into_iter().filter(|item| item.index <= to));
+
+            if reached_end {
+                break;
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Refreshes the TTL of every List Item in the targeted List, see `ListItem::refresh_ttl`.
+    /// All items are fetched then refreshed, up to `concurrency` at once. Only the `Ttl` is
+    /// sent so changes made to an item's data in the meantime aren't overwritten.
+    ///
+    /// Returns the outcome of each update keyed by the List Item index. If the client's circuit
+    /// breaker opens part way through, the remaining updates fail with
+    /// `ErrorKind::CircuitOpen` without being sent.
+    pub async fn refresh_ttl(
+        &self,
+        ttl: u16,
+        concurrency: usize,
+    ) -> Result<Vec<(u32, Result<SyncListItem, TwilioError>)>, TwilioError> {
+        let list_items = self.list(ListParams::default()).await?;
+
+        let results = stream::iter(list_items)
+            .map(|list_item| async move {
+                let result = ListItem {
+                    client: self.client,
+                    service_sid: self.service_sid,
+                    list_sid: self.list_sid,
+                    index: &list_item.index,
+                }
+                .refresh_ttl(ttl)
+                .await;
+
+                (list_item.index, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect::<Vec<(u32, Result<SyncListItem, TwilioError>)>>()
+            .await;
+
         Ok(results)
     }
 }
@@ -209,15 +315,10 @@ impl<'a, 'b> ListItem<'a, 'b> {
     /// argument and fetches the item with the index provided to `listitem()`.
     pub async fn get(&self) -> Result<SyncListItem, TwilioError> {
         self.client
-            .send_request::<SyncListItem, ()>(
-                Method::GET,
-                &format!(
-                    "https://sync.twilio.com/v1/Services/{}/Lists/{}/Items/{}",
-                    self.service_sid, self.list_sid, self.index
-                ),
-                None,
-                None,
-            )
+            .get_resource::<SyncListItem>(&format!(
+                "https://sync.twilio.com/v1/Services/{}/Lists/{}/Items/{}",
+                self.service_sid, self.list_sid, self.index
+            ))
             .await
     }
 
@@ -238,11 +339,8 @@ impl<'a, 'b> ListItem<'a, 'b> {
             ttl: params.ttl,
             collection_ttl: params.collection_ttl,
         };
-        let mut headers = HeaderMap::new();
-
-        if let Some(if_match) = params.if_match.clone() {
-            headers.append("If-Match", if_match.parse().unwrap());
-        }
+        validate_json_object("Data", &params.data)?;
+        let headers = super::if_match_headers(params.if_match.as_deref())?;
 
         self.client
             .send_request::<SyncListItem, UpdateParamsWithJson>(
@@ -257,11 +355,57 @@ impl<'a, 'b> ListItem<'a, 'b> {
             .await
     }
 
+    /// Refreshes the TTL of the List Item provided to `listitem()` so it expires `ttl` seconds from now,
+    /// or never with `0`. Only the `Ttl` is sent so the item's data is left unchanged.
+    ///
+    /// The parent List's TTL isn't extended. Twilio only accepts a `collection_ttl` alongside a
+    /// change to the data or TTL, so set both with `update` if the List should outlive the item.
+    pub async fn refresh_ttl(&self, ttl: u16) -> Result<SyncListItem, TwilioError> {
+        self.client
+            .send_request::<SyncListItem, RefreshTtlParams>(
+                Method::POST,
+                &format!(
+                    "https://sync.twilio.com/v1/Services/{}/Lists/{}/Items/{}",
+                    self.service_sid, self.list_sid, self.index
+                ),
+                Some(&RefreshTtlParams { ttl }),
+                None,
+            )
+            .await
+    }
+
+    /// Updates the List Item only if it hasn't changed since `item` was fetched, by sending
+    /// its `revision` as the `If-Match` precondition. Any `if_match` in `params` is replaced.
+    ///
+    /// Fails with `ErrorKind::Conflict` if the List Item was modified in the meantime, in which
+    /// case it should be fetched again and the change reapplied.
+    pub async fn update_if_unchanged<T>(
+        &self,
+        item: &SyncListItem,
+        params: UpdateParams<'_, T>,
+    ) -> Result<SyncListItem, TwilioError>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.update(UpdateParams {
+            if_match: Some(item.revision.clone()),
+            ..params
+        })
+        .await
+        .map_err(TwilioError::into_conflict)
+    }
+
     /// [Deletes a Sync List Item](https://www.twilio.com/docs/sync/api/listitem-resource#delete-a-listitem-resource)
     ///
     /// Targets the Sync Service provided to the `service()` argument, the List provided to the `list()`
     /// argument and deletes the item with the index provided to `listitem()`.
-    pub async fn delete(&self) -> Result<(), TwilioError> {
+    ///
+    /// If `if_match` is provided the item is only deleted if its revision still matches, otherwise
+    /// the delete is rejected. This can be checked with `TwilioError::is_precondition_failed`.
+    /// `*` matches any revision.
+    pub async fn delete(&self, if_match: Option<&str>) -> Result<(), TwilioError> {
+        let headers = super::if_match_headers(if_match)?;
+
         self.client
             .send_request_and_ignore_response::<()>(
                 Method::DELETE,
@@ -270,8 +414,170 @@ impl<'a, 'b> ListItem<'a, 'b> {
                     self.service_sid, self.list_sid, self.index
                 ),
                 None,
-                None,
+                Some(headers),
             )
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::mock::{serve, test_config, MockResponse};
+    use crate::{Client, Domain, ErrorKind, TwilioConfig};
+
+    #[tokio::test]
+    async fn list_items_ttl_refreshed_despite_failures() {
+        let server = serve([
+            MockResponse::json(r#"{"items":[{"index":0,"account_sid":"AC1","service_sid":"IS1","list_sid":"ES1","url":"","data":{},"date_created":"","date_updated":"","created_by":"system","revision":"1"},{"index":1,"account_sid":"AC1","service_sid":"IS1","list_sid":"ES1","url":"","data":{},"date_created":"","date_updated":"","created_by":"system","revision":"1"},{"index":2,"account_sid":"AC1","service_sid":"IS1","list_sid":"ES1","url":"","data":{},"date_created":"","date_updated":"","created_by":"system","revision":"1"}],"meta":{"page":0,"page_size":3,"first_page_url":"","next_page_url":null,"key":"items"}}"#),
+            MockResponse::json(r#"{"index":0,"account_sid":"AC1","service_sid":"IS1","list_sid":"ES1","url":"","data":{},"date_created":"","date_updated":"","created_by":"system","revision":"1"}"#),
+            MockResponse::json(r#"{"code": 20404, "message": "The requested resource was not found", "more_info": "https://www.twilio.com/docs/errors/20404", "status": 404}"#).status(404),
+            MockResponse::json(r#"{"index":2,"account_sid":"AC1","service_sid":"IS1","list_sid":"ES1","url":"","data":{},"date_created":"","date_updated":"","created_by":"system","revision":"1"}"#),
+        ])
+        .await;
+        let client = Client::new(&test_config()).with_base_url(Domain::Sync, &server.url());
+
+        let results = client
+            .sync()
+            .service("IS1")
+            .list("ES1")
+            .listitems()
+            .refresh_ttl(3600, 1)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            results
+                .iter()
+                .map(|(index, _)| *index)
+                .collect::<Vec<u32>>(),
+            vec![0, 1, 2]
+        );
+        assert!(results[0].1.is_ok());
+        assert!(results[1].1.as_ref().unwrap_err().is_not_found());
+        assert!(results[2].1.is_ok());
+        let requests = server.requests();
+        assert!(requests[3].starts_with("POST /v1/Services/IS1/Lists/ES1/Items/2 "));
+        assert!(requests[3].ends_with("Ttl=3600"));
+    }
+
+    #[tokio::test]
+    async fn list_item_range_stops_at_end_index() {
+        let server = serve([MockResponse::json(r#"{"items":[{"index":3,"account_sid":"AC1","service_sid":"IS1","list_sid":"ES1","url":"","data":{},"date_created":"","date_updated":"","created_by":"system","revision":"0"},{"index":5,"account_sid":"AC1","service_sid":"IS1","list_sid":"ES1","url":"","data":{},"date_created":"","date_updated":"","created_by":"system","revision":"0"}],"meta":{"page":0,"page_size":2,"first_page_url":"","next_page_url":null,"key":"items"}}"#)]).await;
+        let client = Client::new(&test_config()).with_base_url(Domain::Sync, &server.url());
+
+        let items = client
+            .sync()
+            .service("IS1")
+            .list("ES1")
+            .listitems()
+            .get_range(3, 4)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            items.iter().map(|item| item.index).collect::<Vec<u32>>(),
+            vec![3]
+        );
+        let request = server.request();
+        assert!(request.starts_with(
+            "GET /v1/Services/IS1/Lists/ES1/Items?PageSize=2&Order=Asc&From=3&Bounds=Inclusive "
+        ));
+    }
+
+    #[tokio::test]
+    async fn empty_list_item_range_sends_no_request() {
+        // No credentials so any request sent would fail.
+        let client = Client::new(&TwilioConfig::default());
+
+        let items = client
+            .sync()
+            .service("IS1")
+            .list("ES1")
+            .listitems()
+            .get_range(5, 4)
+            .await
+            .unwrap();
+
+        assert!(items.is_empty());
+    }
+
+    #[tokio::test]
+    async fn missing_list_item_is_twilio_error() {
+        let server = serve([MockResponse::json(r#"{"code": 20404, "message": "The requested resource was not found", "more_info": "https://www.twilio.com/docs/errors/20404", "status": 404}"#).status(404)]).await;
+        let client = Client::new(&test_config()).with_base_url(Domain::Sync, &server.url());
+
+        let error = client
+            .sync()
+            .service("IS1")
+            .list("ES1")
+            .listitem(&100)
+            .get()
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error.kind, ErrorKind::TwilioError(ref twilio_error) if twilio_error.status == 404)
+        );
+    }
+
+    #[tokio::test]
+    async fn item_ttl_refreshed_without_data() {
+        let server = serve([MockResponse::json(
+            r#"{"index":0,"account_sid":"AC1","service_sid":"IS1","list_sid":"ES1","url":"","data":{"count":1},"date_created":"","date_updated":"","date_expires":"2024-01-01T01:00:00Z","created_by":"system","revision":"1"}"#,
+        )])
+        .await;
+
+        server
+            .client(Domain::Sync)
+            .sync()
+            .service("IS1")
+            .list("ES1")
+            .listitem(&0)
+            .refresh_ttl(3600)
+            .await
+            .unwrap();
+
+        let request = server.request();
+        assert!(request.starts_with("POST /v1/Services/IS1/Lists/ES1/Items/0 "));
+        assert_eq!(request.split_once("\r\n\r\n").unwrap().1, "Ttl=3600");
+    }
+
+    #[tokio::test]
+    async fn invalid_delete_revision_rejected_before_sending() {
+        let client = Client::new(&test_config());
+
+        let error = client
+            .sync()
+            .service("IS1")
+            .list("ES1")
+            .listitem(&0)
+            .delete(Some("7\n"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.kind, ErrorKind::ValidationError(_)));
+        assert_eq!(client.stats().attempts, 0);
+    }
+
+    #[tokio::test]
+    async fn invalid_update_revision_rejected_before_sending() {
+        let client = Client::new(&test_config());
+
+        let error = client
+            .sync()
+            .service("IS1")
+            .list("ES1")
+            .listitem(&0)
+            .update(super::UpdateParams {
+                if_match: Some(String::from("7\n")),
+                data: &serde_json::json!({"count": 1}),
+                ttl: None,
+                collection_ttl: None,
+            })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.kind, ErrorKind::ValidationError(_)));
+        assert_eq!(client.stats().attempts, 0);
+    }
+}
@@ -4,7 +4,8 @@ Contains Twilio Sync List related functionality.
 
 */
 
-use crate::{Client, PageMeta, TwilioError};
+use crate::{Client, ListPage, Page, PageMeta, TwilioError};
+use futures::Stream;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -19,8 +20,19 @@ pub struct SyncListPage {
     meta: PageMeta,
 }
 
+impl Page for SyncListPage {
+    type Item = SyncList;
+
+    fn into_list_page(self) -> ListPage<SyncList> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.lists,
+        }
+    }
+}
+
 /// A Sync List resource.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SyncList {
     pub sid: String,
     pub unique_name: String,
@@ -44,13 +56,28 @@ pub struct Links {
     pub permissions: String,
 }
 
-/// Parameters for creating a Sync List
+/// Parameters for creating a Sync List, e.g. `CreateParams::default().unique_name("example")`.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Default, Serialize)]
 #[serde(rename_all(serialize = "PascalCase"))]
 pub struct CreateParams {
     pub unique_name: Option<String>,
-    pub ttl: Option<bool>,
+    /// How long the List should exist before deletion (in seconds).
+    pub ttl: Option<u16>,
+}
+
+impl CreateParams {
+    /// Unique name of the List, usable in place of its SID.
+    pub fn unique_name(mut self, unique_name: impl Into<String>) -> Self {
+        self.unique_name = Some(unique_name.into());
+        self
+    }
+
+    /// How long the List should exist before deletion (in seconds).
+    pub fn ttl(mut self, ttl: u16) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
 }
 
 /// Parameters for updating a Sync List
@@ -88,38 +115,41 @@ impl<'a, 'b> Lists<'a, 'b> {
     ///
     /// Lists Sync Lists existing on the Twilio account.
     ///
-    /// Lists will be _eagerly_ paged until all retrieved.
+    /// Lists will be _eagerly_ paged until all retrieved. See `list_paged` to page lazily.
     pub async fn list(&self) -> Result<Vec<SyncList>, TwilioError> {
-        let mut lists_page = self
-            .client
-            .send_request::<SyncListPage, ()>(
-                Method::GET,
-                &format!(
-                    "https://sync.twilio.com/v1/Services/{}/Lists?PageSize=50",
-                    self.service_sid
-                ),
-                None,
-                None,
-            )
-            .await?;
-
-        let mut results: Vec<SyncList> = lists_page.lists;
-
-        while (lists_page.meta.next_page_url).is_some() {
-            lists_page = self
-                .client
-                .send_request::<SyncListPage, ()>(
-                    Method::GET,
-                    &lists_page.meta.next_page_url.unwrap(),
-                    None,
-                    None,
-                )
-                .await?;
-
-            results.append(&mut lists_page.lists);
-        }
+        self.client.collect_pages(self.list_paged()).await
+    }
+
+    /// Lists Lists created after `date_created_after` and before `date_created_before`,
+    /// e.g. to find stale Lists to expire. Either bound can be omitted.
+    ///
+    /// Twilio doesn't support filtering Sync Lists by creation time so all Lists are
+    /// eagerly fetched and filtered on `date_created`. Lists with a `date_created` that
+    /// can't be parsed are included, the same as
+    /// `Conversations::list_updated_after`. Check `date_created` before acting on them.
+    pub async fn list_by_date_created(
+        &self,
+        date_created_after: Option<chrono::DateTime<chrono::Utc>>,
+        date_created_before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<SyncList>, TwilioError> {
+        let mut lists = self.list().await?;
+
+        lists.retain(|list| {
+            super::created_between(&list.date_created, date_created_after, date_created_before)
+        });
 
-        Ok(results)
+        Ok(lists)
+    }
+
+    /// Lists lists a page at a time, see [`ListPage`].
+    pub fn list_paged(&self) -> impl Stream<Item = Result<ListPage<SyncList>, TwilioError>> + '_ {
+        self.client.list_pages::<SyncListPage, ()>(
+            format!(
+                "https://sync.twilio.com/v1/Services/{}/Lists?PageSize=50",
+                self.service_sid
+            ),
+            None,
+        )
     }
 }
 
@@ -137,15 +167,10 @@ impl<'a, 'b> List<'a, 'b> {
     /// provided to the `list()` argument.
     pub async fn get(&self) -> Result<SyncList, TwilioError> {
         self.client
-            .send_request::<SyncList, ()>(
-                Method::GET,
-                &format!(
-                    "https://sync.twilio.com/v1/Services/{}/Lists/{}",
-                    self.service_sid, self.sid
-                ),
-                None,
-                None,
-            )
+            .get_resource::<SyncList>(&format!(
+                "https://sync.twilio.com/v1/Services/{}/Lists/{}",
+                self.service_sid, self.sid
+            ))
             .await
     }
 
@@ -199,7 +224,7 @@ impl<'a, 'b> List<'a, 'b> {
         }
     }
 
-    /// General Sync Map Item functions.
+    /// General Sync List Item functions.
     pub fn listitems(&'a self) -> ListItems {
         ListItems {
             client: self.client,
@@ -208,3 +233,63 @@ impl<'a, 'b> List<'a, 'b> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{serve, test_config, MockResponse};
+    use std::sync::{Arc, Mutex};
+
+    use crate::{Client, Domain};
+
+    #[tokio::test]
+    async fn sync_list_created_then_deleted() {
+        let server = serve([MockResponse::json(r#"{"sid":"ES1","unique_name":"queue","account_sid":"AC1","service_sid":"IS1","url":"","date_created":"2024-01-01T00:00:00Z","date_updated":"2024-01-01T00:00:00Z","date_expires":"2024-01-01T01:00:00Z","created_by":"system","links":{"items":"","permissions":""},"revision":"0"}"#).status(201), MockResponse::new(204)]).await;
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let observed_requests = requests.clone();
+        let client = Client::new(&test_config())
+            .with_base_url(Domain::Sync, &server.url())
+            .with_response_observer(move |meta| {
+                observed_requests.lock().unwrap().push((
+                    meta.method.clone(),
+                    meta.url.clone(),
+                    meta.status,
+                ))
+            });
+
+        let list = client
+            .sync()
+            .service("IS1")
+            .lists()
+            .create(CreateParams::default().unique_name("queue").ttl(3600))
+            .await
+            .unwrap();
+        assert_eq!(list.sid, "ES1");
+        assert_eq!(list.unique_name, "queue");
+        assert_eq!(list.date_expires.as_deref(), Some("2024-01-01T01:00:00Z"));
+
+        client
+            .sync()
+            .service("IS1")
+            .list(&list.sid)
+            .delete()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                (
+                    Method::POST,
+                    format!("{}/v1/Services/IS1/Lists", server.url()),
+                    201
+                ),
+                (
+                    Method::DELETE,
+                    format!("{}/v1/Services/IS1/Lists/ES1", server.url()),
+                    204
+                ),
+            ]
+        );
+    }
+}
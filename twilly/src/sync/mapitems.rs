@@ -4,8 +4,12 @@ Contains Twilio Sync Map Item related functionality.
 
 */
 
-use crate::{Client, PageMeta, TwilioError};
-use reqwest::{header::HeaderMap, Method};
+use crate::{
+    idempotency_headers, page_size, util::validate_json_object, Client, ErrorKind, ListPage,
+    ListProgress, Page, PageMeta, TwilioError,
+};
+use futures::{future::Either, stream, Stream, StreamExt};
+use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
@@ -18,8 +22,19 @@ pub struct MapItemPage {
     pub meta: PageMeta,
 }
 
+impl Page for MapItemPage {
+    type Item = SyncMapItem;
+
+    fn into_list_page(self) -> ListPage<SyncMapItem> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.items,
+        }
+    }
+}
+
 /// A Sync Map Item resource.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SyncMapItem {
     pub key: String,
     pub account_sid: String,
@@ -50,6 +65,8 @@ where
     pub ttl: Option<u16>,
     /// How long the *parent* Map resource should exist before deletion (in seconds).
     pub collection_ttl: Option<u16>,
+    /// Sent as the `I-Twilio-Idempotency-Token` header, see `IDEMPOTENCY_TOKEN_HEADER`.
+    pub idempotency_token: Option<String>,
 }
 
 /// Parameters for creating a Sync Map Item with
@@ -82,7 +99,7 @@ pub enum Bounds {
 
 /// Arguments for listing Sync Map Items
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Default, Serialize)]
 #[serde(rename_all(serialize = "PascalCase"))]
 pub struct ListParams {
     pub order: Option<Order>,
@@ -90,6 +107,13 @@ pub struct ListParams {
     pub from: Option<String>,
     /// Whether to include the Map Item described by the `from` parameter. Defaults to inclusive.
     pub bounds: Option<Bounds>,
+    /// Number of items fetched per request, between 1 and 1000. Defaults to 50. Larger
+    /// pages need fewer requests to list every item.
+    #[serde(skip)]
+    pub page_size: Option<u16>,
+    /// Most items to return. Paging stops once reached rather than fetching every item.
+    #[serde(skip)]
+    pub limit: Option<usize>,
 }
 
 /// Parameters for updating a Sync Map Item
@@ -113,7 +137,8 @@ where
 #[derive(Serialize)]
 #[serde(rename_all(serialize = "PascalCase"))]
 struct UpdateParamsWithJson {
-    #[serde(rename(serialize = "If-Match"))]
+    /// Sent as the `If-Match` header rather than in the body.
+    #[serde(skip)]
     if_match: Option<String>,
     /// Any value that can be represented as JSON
     data: String,
@@ -124,6 +149,13 @@ struct UpdateParamsWithJson {
     collection_ttl: Option<u16>,
 }
 
+/// Parameters for refreshing the TTL of a Sync Map Item, leaving its data unchanged.
+#[derive(Serialize)]
+#[serde(rename_all(serialize = "PascalCase"))]
+struct RefreshTtlParams {
+    ttl: u16,
+}
+
 pub struct MapItems<'a, 'b> {
     pub client: &'a Client,
     pub service_sid: &'b str,
@@ -140,6 +172,7 @@ impl<'a, 'b> MapItems<'a, 'b> {
     {
         // Create a new struct with the provided data parameter converted to a
         // JSON string as required by Twilio.
+        let headers = idempotency_headers(params.idempotency_token.as_deref())?;
         let params = CreateParamsWithJson {
             key: params.key,
             data: serde_json::to_string(params.data)
@@ -147,6 +180,7 @@ impl<'a, 'b> MapItems<'a, 'b> {
             ttl: params.ttl,
             collection_ttl: params.collection_ttl,
         };
+        validate_json_object("Data", &params.data)?;
 
         self.client
             .send_request::<SyncMapItem, CreateParamsWithJson>(
@@ -156,7 +190,7 @@ impl<'a, 'b> MapItems<'a, 'b> {
                     self.service_sid, self.map_sid
                 ),
                 Some(&params),
-                None,
+                headers,
             )
             .await
     }
@@ -168,39 +202,108 @@ impl<'a, 'b> MapItems<'a, 'b> {
     /// Targets the Sync Service provided to the `service()` argument, the Map provided to the `map()`
     /// argument and lists all Map items.
     ///
-    /// Map items will be _eagerly_ paged until all retrieved.
+    /// Map items will be _eagerly_ paged until all retrieved, or `limit` is reached. See
+    /// `list_paged` to page lazily.
     pub async fn list(&self, params: ListParams) -> Result<Vec<SyncMapItem>, TwilioError> {
-        let mut map_items_page = self
-            .client
-            .send_request::<MapItemPage, ListParams>(
-                Method::GET,
-                &format!(
-                    "https://sync.twilio.com/v1/Services/{}/Maps/{}/Items?PageSize=50",
-                    self.service_sid, self.map_sid
+        let limit = params.limit;
+        self.client
+            .collect_pages_with_progress(self.list_paged(params), limit, |_| ())
+            .await
+    }
+
+    /// Lists Map items as `list` does, calling `on_page` with the pages and items fetched so
+    /// far after each page. Useful for reporting progress while paging through large lists.
+    pub async fn list_with_progress(
+        &self,
+        params: ListParams,
+        on_page: impl FnMut(ListProgress),
+    ) -> Result<Vec<SyncMapItem>, TwilioError> {
+        let limit = params.limit;
+        self.client
+            .collect_pages_with_progress(self.list_paged(params), limit, on_page)
+            .await
+    }
+
+    /// Lists map items a page at a time, see [`ListPage`].
+    pub fn list_paged(
+        &self,
+        params: ListParams,
+    ) -> impl Stream<Item = Result<ListPage<SyncMapItem>, TwilioError>> + '_ {
+        match page_size(params.page_size) {
+            Ok(page_size) => Either::Left(self.client.list_pages::<MapItemPage, ListParams>(
+                format!(
+                    "https://sync.twilio.com/v1/Services/{}/Maps/{}/Items?PageSize={}",
+                    self.service_sid, self.map_sid, page_size
                 ),
-                Some(&params),
-                None,
-            )
-            .await?;
-
-        let mut results: Vec<SyncMapItem> = map_items_page.items;
-
-        while (map_items_page.meta.next_page_url).is_some() {
-            map_items_page = self
-                .client
-                .send_request::<MapItemPage, ListParams>(
-                    Method::GET,
-                    &map_items_page.meta.next_page_url.unwrap(),
-                    None,
-                    None,
-                )
-                .await?;
-
-            results.append(&mut map_items_page.items);
+                Some(params),
+            )),
+            Err(error) => Either::Right(stream::once(async move { Err(error) })),
         }
+    }
+
+    /// Refreshes the TTL of every Map Item in the targeted Map, see `MapItem::refresh_ttl`.
+    /// All items are fetched then refreshed, up to `concurrency` at once. Only the `Ttl` is
+    /// sent so changes made to an item's data in the meantime aren't overwritten.
+    ///
+    /// Returns the outcome of each update keyed by the Map Item key. If the client's circuit
+    /// breaker opens part way through, the remaining updates fail with
+    /// `ErrorKind::CircuitOpen` without being sent.
+    pub async fn refresh_ttl(
+        &self,
+        ttl: u16,
+        concurrency: usize,
+    ) -> Result<Vec<(String, Result<SyncMapItem, TwilioError>)>, TwilioError> {
+        let map_items = self.list(ListParams::default()).await?;
+
+        let results = stream::iter(map_items)
+            .map(|map_item| async move {
+                let result = MapItem {
+                    client: self.client,
+                    service_sid: self.service_sid,
+                    map_sid: self.map_sid,
+                    key: &map_item.key,
+                }
+                .refresh_ttl(ttl)
+                .await;
+
+                (map_item.key, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect::<Vec<(String, Result<SyncMapItem, TwilioError>)>>()
+            .await;
 
         Ok(results)
     }
+
+    /// Creates a Map Item for each key & data pair of `items`, up to `concurrency` at once,
+    /// e.g. to migrate items from another Map or a JSON file. Failed creates are retried as
+    /// configured on the client.
+    ///
+    /// Returns the outcome of each create keyed by the Map Item key, so the keys imported
+    /// can be told apart from those that failed.
+    pub async fn import(
+        &self,
+        items: impl IntoIterator<Item = (String, Value)>,
+        concurrency: usize,
+    ) -> Vec<(String, Result<SyncMapItem, TwilioError>)> {
+        stream::iter(items)
+            .map(|(key, data)| async move {
+                let result = self
+                    .create(CreateParams {
+                        key: key.clone(),
+                        data: &data,
+                        ttl: None,
+                        collection_ttl: None,
+                        idempotency_token: None,
+                    })
+                    .await;
+
+                (key, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
 }
 
 pub struct MapItem<'a, 'b> {
@@ -218,15 +321,10 @@ impl<'a, 'b> MapItem<'a, 'b> {
     /// argument and fetches the item with the key provided to `mapitem()`.
     pub async fn get(&self) -> Result<SyncMapItem, TwilioError> {
         self.client
-            .send_request::<SyncMapItem, ()>(
-                Method::GET,
-                &format!(
-                    "https://sync.twilio.com/v1/Services/{}/Maps/{}/Items/{}",
-                    self.service_sid, self.map_sid, self.key
-                ),
-                None,
-                None,
-            )
+            .get_resource::<SyncMapItem>(&format!(
+                "https://sync.twilio.com/v1/Services/{}/Maps/{}/Items/{}",
+                self.service_sid, self.map_sid, self.key
+            ))
             .await
     }
 
@@ -247,12 +345,9 @@ impl<'a, 'b> MapItem<'a, 'b> {
             ttl: params.ttl,
             collection_ttl: params.collection_ttl,
         };
+        validate_json_object("Data", &params.data)?;
 
-        let mut headers = HeaderMap::new();
-
-        if let Some(if_match) = params.if_match.clone() {
-            headers.append("If-Match", if_match.parse().unwrap());
-        }
+        let headers = super::if_match_headers(params.if_match.as_deref())?;
 
         self.client
             .send_request::<SyncMapItem, UpdateParamsWithJson>(
@@ -267,11 +362,89 @@ impl<'a, 'b> MapItem<'a, 'b> {
             .await
     }
 
+    /// Refreshes the TTL of the Map Item provided to `mapitem()` so it expires `ttl` seconds from now,
+    /// or never with `0`. Only the `Ttl` is sent so the item's data is left unchanged.
+    ///
+    /// The parent Map's TTL isn't extended. Twilio only accepts a `collection_ttl` alongside a
+    /// change to the data or TTL, so set both with `update` if the Map should outlive the item.
+    pub async fn refresh_ttl(&self, ttl: u16) -> Result<SyncMapItem, TwilioError> {
+        self.client
+            .send_request::<SyncMapItem, RefreshTtlParams>(
+                Method::POST,
+                &format!(
+                    "https://sync.twilio.com/v1/Services/{}/Maps/{}/Items/{}",
+                    self.service_sid, self.map_sid, self.key
+                ),
+                Some(&RefreshTtlParams { ttl }),
+                None,
+            )
+            .await
+    }
+
+    /// Updates the Map Item only if it hasn't changed since `item` was fetched, by sending
+    /// its `revision` as the `If-Match` precondition. Any `if_match` in `params` is replaced.
+    ///
+    /// Fails with `ErrorKind::Conflict` if the Map Item was modified in the meantime, in which
+    /// case it should be fetched again and the change reapplied.
+    pub async fn update_if_unchanged<T>(
+        &self,
+        item: &SyncMapItem,
+        params: UpdateParams<'_, T>,
+    ) -> Result<SyncMapItem, TwilioError>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.update(UpdateParams {
+            if_match: Some(item.revision.clone()),
+            ..params
+        })
+        .await
+        .map_err(TwilioError::into_conflict)
+    }
+
+    /// Renames the Map Item provided to `mapitem()` to `new_key`. The item is fetched, re-created
+    /// under the new key with the same data and remaining TTL, then the original is deleted.
+    ///
+    /// This is not atomic. Should the delete fail the item will exist under both keys. The original
+    /// is only deleted if it hasn't changed since being fetched.
+    ///
+    /// A TTL is sent in seconds as a `u16` so items expiring more than `u16::MAX` seconds (~18 hours)
+    /// from now can't keep their expiry. These are rejected with a validation error before anything
+    /// is created.
+    pub async fn rename(&self, new_key: &str) -> Result<SyncMapItem, TwilioError> {
+        let map_item = self.get().await?;
+        let ttl = remaining_ttl(map_item.date_expires.as_deref())?;
+
+        let renamed_map_item = MapItems {
+            client: self.client,
+            service_sid: self.service_sid,
+            map_sid: self.map_sid,
+        }
+        .create(CreateParams {
+            key: new_key.to_string(),
+            data: &map_item.data,
+            ttl,
+            collection_ttl: None,
+            idempotency_token: None,
+        })
+        .await?;
+
+        self.delete(Some(&map_item.revision)).await?;
+
+        Ok(renamed_map_item)
+    }
+
     /// [Deletes a Sync Map Item](https://www.twilio.com/docs/sync/api/map-item-resource#delete-a-mapitem-resource)
     ///
     /// Targets the Sync Service provided to the `service()` argument, the Map provided to the `map()`
     /// argument and deletes the item with the key provided to `mapitem()`.
-    pub async fn delete(&self) -> Result<(), TwilioError> {
+    ///
+    /// If `if_match` is provided the item is only deleted if its revision still matches, otherwise
+    /// the delete is rejected. This can be checked with `TwilioError::is_precondition_failed`.
+    /// `*` matches any revision.
+    pub async fn delete(&self, if_match: Option<&str>) -> Result<(), TwilioError> {
+        let headers = super::if_match_headers(if_match)?;
+
         self.client
             .send_request_and_ignore_response::<()>(
                 Method::DELETE,
@@ -280,8 +453,333 @@ impl<'a, 'b> MapItem<'a, 'b> {
                     self.service_sid, self.map_sid, self.key
                 ),
                 None,
-                None,
+                Some(headers),
+            )
+            .await
+    }
+}
+
+// Seconds until `date_expires`, an ISO8601 timestamp. `None` if the item doesn't expire or the
+// expiry can't be determined. An expiry further away than the largest TTL that can be sent is an
+// error rather than being shortened.
+fn remaining_ttl(date_expires: Option<&str>) -> Result<Option<u16>, TwilioError> {
+    let Some(date_expires) = date_expires
+        .and_then(|date_expires| chrono::DateTime::parse_from_rfc3339(date_expires).ok())
+    else {
+        return Ok(None);
+    };
+    let remaining = (date_expires.with_timezone(&chrono::Utc) - chrono::Utc::now()).num_seconds();
+
+    u16::try_from(remaining.max(1))
+        .map(Some)
+        .map_err(|_| TwilioError {
+            kind: ErrorKind::ValidationError(format!(
+                "Item expires in {} seconds, longer than the maximum TTL of {} seconds",
+                remaining,
+                u16::MAX
+            )),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{serve, test_config, MockResponse};
+    use std::sync::{Arc, Mutex};
+
+    use crate::{Client, Domain, ErrorKind, MAX_PAGE_SIZE};
+
+    #[tokio::test]
+    async fn map_items_listed_with_page_size() {
+        let server = serve([MockResponse::json(r#"{"items":[],"meta":{"page":0,"page_size":1000,"first_page_url":"","next_page_url":null,"key":"items"}}"#)]).await;
+        let client = Client::new(&test_config()).with_base_url(Domain::Sync, &server.url());
+
+        let items = client
+            .sync()
+            .service("IS1")
+            .map("MP1")
+            .mapitems()
+            .list(ListParams {
+                page_size: Some(MAX_PAGE_SIZE),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert!(items.is_empty());
+        let request = server.request();
+        assert!(request.starts_with("GET /v1/Services/IS1/Maps/MP1/Items?PageSize=1000 "));
+    }
+
+    #[tokio::test]
+    async fn map_items_ttl_refreshed_despite_failures() {
+        let server = serve([
+            MockResponse::json(r#"{"items":[{"key":"a","account_sid":"AC1","service_sid":"IS1","map_sid":"MP1","url":"","data":{},"date_created":"","date_updated":"","date_expires":null,"created_by":"system","revision":"1"},{"key":"b","account_sid":"AC1","service_sid":"IS1","map_sid":"MP1","url":"","data":{},"date_created":"","date_updated":"","date_expires":null,"created_by":"system","revision":"1"},{"key":"c","account_sid":"AC1","service_sid":"IS1","map_sid":"MP1","url":"","data":{},"date_created":"","date_updated":"","date_expires":null,"created_by":"system","revision":"1"}],"meta":{"page":0,"page_size":3,"first_page_url":"","next_page_url":null,"key":"items"}}"#),
+            MockResponse::json(r#"{"key":"a","account_sid":"AC1","service_sid":"IS1","map_sid":"MP1","url":"","data":{},"date_created":"","date_updated":"","date_expires":null,"created_by":"system","revision":"1"}"#),
+            MockResponse::json(r#"{"code": 20404, "message": "The requested resource was not found", "more_info": "https://www.twilio.com/docs/errors/20404", "status": 404}"#).status(404),
+            MockResponse::json(r#"{"key":"c","account_sid":"AC1","service_sid":"IS1","map_sid":"MP1","url":"","data":{},"date_created":"","date_updated":"","date_expires":null,"created_by":"system","revision":"1"}"#),
+        ])
+        .await;
+        let client = Client::new(&test_config()).with_base_url(Domain::Sync, &server.url());
+
+        let results = client
+            .sync()
+            .service("IS1")
+            .map("MP1")
+            .mapitems()
+            .refresh_ttl(3600, 1)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            results
+                .iter()
+                .map(|(key, _)| key.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["a", "b", "c"]
+        );
+        assert!(results[0].1.is_ok());
+        assert!(results[1].1.as_ref().unwrap_err().is_not_found());
+        assert!(results[2].1.is_ok());
+        let requests = server.requests();
+        assert!(requests[3].starts_with("POST /v1/Services/IS1/Maps/MP1/Items/c "));
+        assert!(requests[3].ends_with("Ttl=3600"));
+    }
+
+    #[tokio::test]
+    async fn map_items_progress_reported_per_page() {
+        let server = serve([MockResponse::json(r#"{"items":[{"key":"a","account_sid":"AC1","service_sid":"IS1","map_sid":"MP1","url":"","data":{},"date_created":"","date_updated":"","date_expires":null,"created_by":"system","revision":"0"},{"key":"b","account_sid":"AC1","service_sid":"IS1","map_sid":"MP1","url":"","data":{},"date_created":"","date_updated":"","date_expires":null,"created_by":"system","revision":"0"}],"meta":{"page":0,"page_size":2,"first_page_url":"","next_page_url":"https://sync.twilio.com/v1/Services/IS1/Maps/MP1/Items?PageSize=2&Page=1","key":"items"}}"#), MockResponse::json(r#"{"items":[{"key":"c","account_sid":"AC1","service_sid":"IS1","map_sid":"MP1","url":"","data":{},"date_created":"","date_updated":"","date_expires":null,"created_by":"system","revision":"0"}],"meta":{"page":1,"page_size":2,"first_page_url":"","next_page_url":null,"key":"items"}}"#)]).await;
+        let client = Client::new(&test_config()).with_base_url(Domain::Sync, &server.url());
+        let mut progress = Vec::new();
+
+        let items = client
+            .sync()
+            .service("IS1")
+            .map("MP1")
+            .mapitems()
+            .list_with_progress(
+                ListParams {
+                    page_size: Some(2),
+                    ..Default::default()
+                },
+                |page_progress| progress.push(page_progress),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(items.len(), 3);
+        assert_eq!(
+            progress,
+            vec![
+                ListProgress { pages: 1, items: 2 },
+                ListProgress { pages: 2, items: 3 },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn map_items_listed_up_to_limit() {
+        fn page(first_key: usize, next_page_url: &str) -> MockResponse {
+            let items = (first_key..first_key + 50)
+                .map(|key| {
+                    format!(
+                        r#"{{"key":"{}","account_sid":"AC1","service_sid":"IS1","map_sid":"MP1","url":"","data":{{}},"date_created":"","date_updated":"","date_expires":null,"created_by":"system","revision":"0"}}"#,
+                        key
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(",");
+            let body = format!(
+                r#"{{"items":[{}],"meta":{{"page":0,"page_size":50,"first_page_url":"","next_page_url":"{}","key":"items"}}}}"#,
+                items, next_page_url
+            );
+
+            MockResponse::json(&body)
+        }
+
+        let responses = vec![
+            page(
+                0,
+                "https://sync.twilio.com/v1/Services/IS1/Maps/MP1/Items?PageSize=50&Page=1",
+            ),
+            page(
+                50,
+                "https://sync.twilio.com/v1/Services/IS1/Maps/MP1/Items?PageSize=50&Page=2",
+            ),
+            // Never requested as the limit is reached on the second page.
+            page(
+                100,
+                "https://sync.twilio.com/v1/Services/IS1/Maps/MP1/Items?PageSize=50&Page=3",
+            ),
+        ];
+        let server = serve(responses).await;
+        let requests = Arc::new(Mutex::new(0));
+        let observed_requests = requests.clone();
+        let client = Client::new(&test_config())
+            .with_base_url(Domain::Sync, &server.url())
+            .with_response_observer(move |_| *observed_requests.lock().unwrap() += 1);
+
+        let items = client
+            .sync()
+            .service("IS1")
+            .map("MP1")
+            .mapitems()
+            .list(ListParams {
+                limit: Some(60),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(items.len(), 60);
+        assert_eq!(items.last().unwrap().key, "59");
+        assert_eq!(*requests.lock().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn map_items_import_reports_failed_keys() {
+        let server = serve([MockResponse::json(r#"{"key":"a","account_sid":"AC1","service_sid":"IS1","map_sid":"MP1","url":"","data":{"n":1},"date_created":"","date_updated":"","date_expires":null,"created_by":"system","revision":"0"}"#).status(201), MockResponse::json(r#"{"code":54208,"message":"Unique name already exists","more_info":"https://www.twilio.com/docs/errors/54208","status":409}"#).status(409), MockResponse::json(r#"{"key":"c","account_sid":"AC1","service_sid":"IS1","map_sid":"MP1","url":"","data":{"n":1},"date_created":"","date_updated":"","date_expires":null,"created_by":"system","revision":"0"}"#).status(201)]).await;
+        let client = Client::new(&test_config()).with_base_url(Domain::Sync, &server.url());
+
+        let results = client
+            .sync()
+            .service("IS1")
+            .map("MP1")
+            .mapitems()
+            .import(
+                ["a", "b", "c"].map(|key| (String::from(key), serde_json::json!({ "n": 1 }))),
+                1,
+            )
+            .await;
+
+        let imported = results
+            .iter()
+            .filter(|(_, result)| result.is_ok())
+            .map(|(key, _)| key.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(imported, vec!["a", "c"]);
+        assert!(matches!(
+            &results[1],
+            (key, Err(TwilioError { kind: ErrorKind::TwilioError(error) })) if key == "b" && error.code == 54208
+        ));
+    }
+
+    #[tokio::test]
+    async fn stale_map_item_update_is_conflict() {
+        let server = serve([MockResponse::json(r#"{"code":54103,"message":"The revision of the Map Item does not match the expected revision","more_info":"","status":412}"#).status(412)]).await;
+        let client = Client::new(&test_config()).with_base_url(Domain::Sync, &server.url());
+        let item: SyncMapItem = serde_json::from_value(serde_json::json!({
+            "key": "counter",
+            "account_sid": "AC1",
+            "service_sid": "IS1",
+            "map_sid": "MP1",
+            "url": "",
+            "data": { "count": 1 },
+            "date_created": "",
+            "date_updated": "",
+            "date_expires": null,
+            "created_by": "system",
+            "revision": "7"
+        }))
+        .unwrap();
+        let sync = client.sync();
+        let service = sync.service("IS1");
+        let map = service.map("MP1");
+
+        let error = map
+            .mapitem("counter")
+            .update_if_unchanged(
+                &item,
+                UpdateParams {
+                    if_match: None,
+                    data: &serde_json::json!({ "count": 2 }),
+                    ttl: None,
+                    collection_ttl: None,
+                },
             )
             .await
+            .unwrap_err();
+
+        assert!(matches!(error.kind, ErrorKind::Conflict(_)));
+        assert!(error.is_precondition_failed());
+        let request = server.request();
+        assert!(request
+            .lines()
+            .any(|line| line.eq_ignore_ascii_case("if-match: 7")));
+        assert!(!request.contains("If-Match="));
+    }
+
+    #[tokio::test]
+    async fn item_ttl_refreshed_without_data() {
+        let server = serve([MockResponse::json(
+            r#"{"key":"a","account_sid":"AC1","service_sid":"IS1","map_sid":"MP1","url":"","data":{"count":1},"date_created":"","date_updated":"","date_expires":"2024-01-01T01:00:00Z","created_by":"system","revision":"1"}"#,
+        )])
+        .await;
+
+        server
+            .client(Domain::Sync)
+            .sync()
+            .service("IS1")
+            .map("MP1")
+            .mapitem("a")
+            .refresh_ttl(3600)
+            .await
+            .unwrap();
+
+        let request = server.request();
+        assert!(request.starts_with("POST /v1/Services/IS1/Maps/MP1/Items/a "));
+        assert_eq!(request.split_once("\r\n\r\n").unwrap().1, "Ttl=3600");
+    }
+
+    #[tokio::test]
+    async fn invalid_delete_revision_rejected_before_sending() {
+        let client = Client::new(&test_config());
+
+        let error = client
+            .sync()
+            .service("IS1")
+            .map("MP1")
+            .mapitem("counter")
+            .delete(Some("7\n"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.kind, ErrorKind::ValidationError(_)));
+        assert_eq!(client.stats().attempts, 0);
+    }
+
+    #[tokio::test]
+    async fn invalid_update_revision_rejected_before_sending() {
+        let client = Client::new(&test_config());
+
+        let error = client
+            .sync()
+            .service("IS1")
+            .map("MP1")
+            .mapitem("counter")
+            .update(UpdateParams {
+                if_match: Some(String::from("7\n")),
+                data: &serde_json::json!({"count": 1}),
+                ttl: None,
+                collection_ttl: None,
+            })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.kind, ErrorKind::ValidationError(_)));
+        assert_eq!(client.stats().attempts, 0);
+    }
+
+    #[test]
+    fn remaining_ttl_beyond_maximum_rejected() {
+        let soon = (chrono::Utc::now() + chrono::Duration::hours(1)).to_rfc3339();
+        let ttl = remaining_ttl(Some(&soon)).unwrap().unwrap();
+        assert!((3590..=3600).contains(&ttl));
+
+        let later = (chrono::Utc::now() + chrono::Duration::days(2)).to_rfc3339();
+        let error = remaining_ttl(Some(&later)).unwrap_err();
+        assert!(matches!(error.kind, ErrorKind::ValidationError(_)));
+
+        assert_eq!(remaining_ttl(None).unwrap(), None);
     }
 }
@@ -4,7 +4,8 @@ Contains Twilio Sync Map related functionality.
 
 */
 
-use crate::{Client, PageMeta, TwilioError};
+use crate::{Client, ListPage, Page, PageMeta, TwilioError};
+use futures::Stream;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -19,8 +20,19 @@ pub struct SyncMapPage {
     pub meta: PageMeta,
 }
 
+impl Page for SyncMapPage {
+    type Item = SyncMap;
+
+    fn into_list_page(self) -> ListPage<SyncMap> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.maps,
+        }
+    }
+}
+
 /// A Sync Map resource.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SyncMap {
     pub sid: String,
     pub unique_name: String,
@@ -44,13 +56,28 @@ pub struct Links {
     pub permissions: String,
 }
 
-/// Parameters for creating a Sync Map
+/// Parameters for creating a Sync Map, e.g. `CreateParams::default().unique_name("example")`.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Default, Serialize)]
 #[serde(rename_all(serialize = "PascalCase"))]
 pub struct CreateParams {
     pub unique_name: Option<String>,
-    pub ttl: Option<bool>,
+    /// How long the Map should exist before deletion (in seconds).
+    pub ttl: Option<u16>,
+}
+
+impl CreateParams {
+    /// Unique name of the Map, usable in place of its SID.
+    pub fn unique_name(mut self, unique_name: impl Into<String>) -> Self {
+        self.unique_name = Some(unique_name.into());
+        self
+    }
+
+    /// How long the Map should exist before deletion (in seconds).
+    pub fn ttl(mut self, ttl: u16) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
 }
 
 /// Parameters for updating a Sync Map
@@ -88,38 +115,41 @@ impl<'a, 'b> Maps<'a, 'b> {
     ///
     /// Lists Sync Maps existing on the Twilio account.
     ///
-    /// Maps will be _eagerly_ paged until all retrieved.
+    /// Maps will be _eagerly_ paged until all retrieved. See `list_paged` to page lazily.
     pub async fn list(&self) -> Result<Vec<SyncMap>, TwilioError> {
-        let mut maps_page = self
-            .client
-            .send_request::<SyncMapPage, ()>(
-                Method::GET,
-                &format!(
-                    "https://sync.twilio.com/v1/Services/{}/Maps?PageSize=20",
-                    self.service_sid
-                ),
-                None,
-                None,
-            )
-            .await?;
-
-        let mut results: Vec<SyncMap> = maps_page.maps;
-
-        while (maps_page.meta.next_page_url).is_some() {
-            maps_page = self
-                .client
-                .send_request::<SyncMapPage, ()>(
-                    Method::GET,
-                    &maps_page.meta.next_page_url.unwrap(),
-                    None,
-                    None,
-                )
-                .await?;
-
-            results.append(&mut maps_page.maps);
-        }
+        self.client.collect_pages(self.list_paged()).await
+    }
+
+    /// Lists Maps created after `date_created_after` and before `date_created_before`,
+    /// e.g. to find stale Maps to expire. Either bound can be omitted.
+    ///
+    /// Twilio doesn't support filtering Sync Maps by creation time so all Maps are
+    /// eagerly fetched and filtered on `date_created`. Maps with a `date_created` that
+    /// can't be parsed are included, the same as
+    /// `Conversations::list_updated_after`. Check `date_created` before acting on them.
+    pub async fn list_by_date_created(
+        &self,
+        date_created_after: Option<chrono::DateTime<chrono::Utc>>,
+        date_created_before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<SyncMap>, TwilioError> {
+        let mut maps = self.list().await?;
+
+        maps.retain(|map| {
+            super::created_between(&map.date_created, date_created_after, date_created_before)
+        });
+
+        Ok(maps)
+    }
 
-        Ok(results)
+    /// Lists maps a page at a time, see [`ListPage`].
+    pub fn list_paged(&self) -> impl Stream<Item = Result<ListPage<SyncMap>, TwilioError>> + '_ {
+        self.client.list_pages::<SyncMapPage, ()>(
+            format!(
+                "https://sync.twilio.com/v1/Services/{}/Maps?PageSize=20",
+                self.service_sid
+            ),
+            None,
+        )
     }
 }
 
@@ -137,15 +167,10 @@ impl<'a, 'b> Map<'a, 'b> {
     /// provided to the `map()` argument.
     pub async fn get(&self) -> Result<SyncMap, TwilioError> {
         self.client
-            .send_request::<SyncMap, ()>(
-                Method::GET,
-                &format!(
-                    "https://sync.twilio.com/v1/Services/{}/Maps/{}",
-                    self.service_sid, self.sid
-                ),
-                None,
-                None,
-            )
+            .get_resource::<SyncMap>(&format!(
+                "https://sync.twilio.com/v1/Services/{}/Maps/{}",
+                self.service_sid, self.sid
+            ))
             .await
     }
 
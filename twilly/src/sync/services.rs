@@ -4,7 +4,8 @@ Contains Twilio Sync Service related functionality.
 
 */
 
-use crate::{Client, PageMeta, TwilioError};
+use crate::{Client, ListPage, Page, PageMeta, TwilioError};
+use futures::{stream, StreamExt};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -13,6 +14,7 @@ use super::{
     documents::{Document, Documents},
     lists::{List, Lists},
     maps::{Map, Maps},
+    streams::{Stream, Streams},
 };
 
 /// Represents a page of Sync Services from the Twilio API.
@@ -23,8 +25,20 @@ pub struct SyncServicePage {
     meta: PageMeta,
 }
 
+impl Page for SyncServicePage {
+    type Item = SyncService;
+
+    fn into_list_page(self) -> ListPage<SyncService> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.services,
+        }
+    }
+}
+
 /// A Sync Service resource.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SyncService {
     pub sid: String,
     pub unique_name: Option<String>,
@@ -33,8 +47,13 @@ pub struct SyncService {
     pub date_created: String,
     pub date_updated: String,
     pub url: String,
+    /// URL Twilio sends webhooks to on changes to the Service's resources.
     pub webhook_url: Option<String>,
+    /// Whether changes made via the REST API also trigger webhooks.
     pub webhooks_from_rest_enabled: bool,
+    /// Whether the Service sends `endpoint_connected` and `endpoint_disconnected`
+    /// webhooks as clients connect and disconnect. `None` if omitted by Twilio.
+    pub reachability_webhooks_enabled: Option<bool>,
     /// Requires identities to be granted access to the Sync Service
     pub acl_enabled: bool,
     /// Whether the `endpoint_disconnected` webhook should occur after a
@@ -50,6 +69,7 @@ pub struct SyncService {
 
 /// Resources _linked_ to a Service
 #[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
 pub struct Links {
     pub documents: String,
     pub lists: String,
@@ -58,9 +78,10 @@ pub struct Links {
 }
 
 /// Parameters for creating or updating a Sync Service. See `SyncService` for
-/// details on individual parameters.
+/// details on individual parameters. Options can be chained from the default,
+/// e.g. `CreateOrUpdateParams::default().friendly_name("Example").acl_enabled(true)`.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Default, Serialize)]
 #[serde(rename_all(serialize = "PascalCase"))]
 pub struct CreateOrUpdateParams {
     pub friendly_name: Option<String>,
@@ -72,6 +93,53 @@ pub struct CreateOrUpdateParams {
     pub webhooks_from_rest_enabled: Option<bool>,
 }
 
+impl CreateOrUpdateParams {
+    /// Name of the Service.
+    pub fn friendly_name(mut self, friendly_name: impl Into<String>) -> Self {
+        self.friendly_name = Some(friendly_name.into());
+        self
+    }
+
+    /// URL Twilio sends webhooks to on changes to the Service's resources.
+    pub fn webhook_url(mut self, webhook_url: impl Into<String>) -> Self {
+        self.webhook_url = Some(webhook_url.into());
+        self
+    }
+
+    /// Whether webhooks are sent when a client connects or disconnects.
+    pub fn reachability_webhooks_enabled(mut self, reachability_webhooks_enabled: bool) -> Self {
+        self.reachability_webhooks_enabled = Some(reachability_webhooks_enabled);
+        self
+    }
+
+    /// Whether token identities require permissions to access the Service's resources.
+    pub fn acl_enabled(mut self, acl_enabled: bool) -> Self {
+        self.acl_enabled = Some(acl_enabled);
+        self
+    }
+
+    /// Whether reachability webhooks are delayed so brief disconnections are ignored.
+    pub fn reachability_debouncing_enabled(
+        mut self,
+        reachability_debouncing_enabled: bool,
+    ) -> Self {
+        self.reachability_debouncing_enabled = Some(reachability_debouncing_enabled);
+        self
+    }
+
+    /// Milliseconds reachability webhooks are delayed by when debouncing is enabled.
+    pub fn reachability_debouncing_window(mut self, reachability_debouncing_window: u16) -> Self {
+        self.reachability_debouncing_window = Some(reachability_debouncing_window);
+        self
+    }
+
+    /// Whether webhooks are also sent for changes made via the REST API.
+    pub fn webhooks_from_rest_enabled(mut self, webhooks_from_rest_enabled: bool) -> Self {
+        self.webhooks_from_rest_enabled = Some(webhooks_from_rest_enabled);
+        self
+    }
+}
+
 pub struct Services<'a> {
     pub client: &'a Client,
 }
@@ -102,35 +170,19 @@ impl<'a> Services<'a> {
     ///
     /// List Sync Services existing on the Twilio account.
     ///
-    /// Services will be _eagerly_ paged until all retrieved.
+    /// Services will be _eagerly_ paged until all retrieved. See `list_paged` to page lazily.
     pub async fn list(&self) -> Result<Vec<SyncService>, TwilioError> {
-        let mut services_page = self
-            .client
-            .send_request::<SyncServicePage, ()>(
-                Method::GET,
-                "https://sync.twilio.com/v1/Services?PageSize=20",
-                None,
-                None,
-            )
-            .await?;
-
-        let mut results: Vec<SyncService> = services_page.services;
-
-        while (services_page.meta.next_page_url).is_some() {
-            services_page = self
-                .client
-                .send_request::<SyncServicePage, ()>(
-                    Method::GET,
-                    &services_page.meta.next_page_url.unwrap(),
-                    None,
-                    None,
-                )
-                .await?;
-
-            results.append(&mut services_page.services);
-        }
+        self.client.collect_pages(self.list_paged()).await
+    }
 
-        Ok(results)
+    /// Lists services a page at a time, see [`ListPage`].
+    pub fn list_paged(
+        &self,
+    ) -> impl futures::Stream<Item = Result<ListPage<SyncService>, TwilioError>> + '_ {
+        self.client.list_pages::<SyncServicePage, ()>(
+            String::from("https://sync.twilio.com/v1/Services?PageSize=20"),
+            None,
+        )
     }
 }
 
@@ -145,12 +197,10 @@ impl<'a, 'b> Service<'a, 'b> {
     /// Fetches the Sync Service provided to the `Service()`.
     pub async fn get(&self) -> Result<SyncService, TwilioError> {
         self.client
-            .send_request::<SyncService, ()>(
-                Method::GET,
-                &format!("https://sync.twilio.com/v1/Services/{}", self.sid),
-                None,
-                None,
-            )
+            .get_resource::<SyncService>(&format!(
+                "https://sync.twilio.com/v1/Services/{}",
+                self.sid
+            ))
             .await
     }
 
@@ -191,6 +241,93 @@ impl<'a, 'b> Service<'a, 'b> {
             .await
     }
 
+    /// Deletes every Sync Document in the Sync Service provided to the `Service()` argument,
+    /// up to `concurrency` at once. The Sync Service itself is left in place.
+    ///
+    /// Returns the outcome of each delete keyed by the Sync Document SID.
+    pub async fn delete_all_documents(
+        &self,
+        concurrency: usize,
+    ) -> Result<Vec<(String, Result<(), TwilioError>)>, TwilioError> {
+        let documents = self.documents().list().await?;
+
+        let results = stream::iter(documents)
+            .map(|document| async move {
+                let result = Document {
+                    client: self.client,
+                    service_sid: self.sid,
+                    sid: &document.sid,
+                }
+                .delete()
+                .await;
+
+                (document.sid, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect::<Vec<(String, Result<(), TwilioError>)>>()
+            .await;
+
+        Ok(results)
+    }
+
+    /// Deletes every Sync Map in the Sync Service provided to the `Service()` argument,
+    /// up to `concurrency` at once. The Sync Service itself is left in place.
+    ///
+    /// Returns the outcome of each delete keyed by the Sync Map SID.
+    pub async fn delete_all_maps(
+        &self,
+        concurrency: usize,
+    ) -> Result<Vec<(String, Result<(), TwilioError>)>, TwilioError> {
+        let maps = self.maps().list().await?;
+
+        let results = stream::iter(maps)
+            .map(|map| async move {
+                let result = Map {
+                    client: self.client,
+                    service_sid: self.sid,
+                    sid: &map.sid,
+                }
+                .delete()
+                .await;
+
+                (map.sid, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect::<Vec<(String, Result<(), TwilioError>)>>()
+            .await;
+
+        Ok(results)
+    }
+
+    /// Deletes every Sync List in the Sync Service provided to the `Service()` argument,
+    /// up to `concurrency` at once. The Sync Service itself is left in place.
+    ///
+    /// Returns the outcome of each delete keyed by the Sync List SID.
+    pub async fn delete_all_lists(
+        &self,
+        concurrency: usize,
+    ) -> Result<Vec<(String, Result<(), TwilioError>)>, TwilioError> {
+        let lists = self.lists().list().await?;
+
+        let results = stream::iter(lists)
+            .map(|list| async move {
+                let result = List {
+                    client: self.client,
+                    service_sid: self.sid,
+                    sid: &list.sid,
+                }
+                .delete()
+                .await;
+
+                (list.sid, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect::<Vec<(String, Result<(), TwilioError>)>>()
+            .await;
+
+        Ok(results)
+    }
+
     /// Functions relating to a known Sync Document.
     ///
     /// Takes in the SID of the Sync Document to perform actions against.
@@ -247,6 +384,25 @@ impl<'a, 'b> Service<'a, 'b> {
             sid,
         }
     }
+
+    /// General Sync Stream functions.
+    pub fn streams(&'a self) -> Streams {
+        Streams {
+            client: self.client,
+            service_sid: self.sid,
+        }
+    }
+
+    /// Functions relating to a known Sync Stream.
+    ///
+    /// Takes in the SID of the Sync Stream to perform actions against.
+    pub fn stream(&'a self, sid: &'b str) -> Stream {
+        Stream {
+            client: self.client,
+            service_sid: self.sid,
+            sid,
+        }
+    }
 }
 
 // Validates that the provided `reachability_debouncing_window` is between it's
@@ -270,3 +426,89 @@ fn validate_reachability_debouncing_window(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{serve, test_config, MockResponse};
+    use crate::{Client, Domain};
+
+    #[test]
+    fn service_deserialized_from_twilio_payload() {
+        // Captured from Twilio's fetch Service response.
+        let service: SyncService = serde_json::from_str(
+            r#"{
+                "sid": "IS00000000000000000000000000000000",
+                "unique_name": null,
+                "account_sid": "AC00000000000000000000000000000000",
+                "friendly_name": "Example",
+                "date_created": "2024-01-01T00:00:00Z",
+                "date_updated": "2024-01-02T00:00:00Z",
+                "url": "https://sync.twilio.com/v1/Services/IS00000000000000000000000000000000",
+                "webhook_url": "https://example.com/sync",
+                "webhooks_from_rest_enabled": true,
+                "reachability_webhooks_enabled": true,
+                "acl_enabled": false,
+                "reachability_debouncing_enabled": true,
+                "reachability_debouncing_window": 5000,
+                "links": {
+                    "documents": "https://sync.twilio.com/v1/Services/IS00000000000000000000000000000000/Documents",
+                    "lists": "https://sync.twilio.com/v1/Services/IS00000000000000000000000000000000/Lists",
+                    "maps": "https://sync.twilio.com/v1/Services/IS00000000000000000000000000000000/Maps",
+                    "streams": "https://sync.twilio.com/v1/Services/IS00000000000000000000000000000000/Streams"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(service.friendly_name.as_deref(), Some("Example"));
+        assert_eq!(
+            service.webhook_url.as_deref(),
+            Some("https://example.com/sync")
+        );
+        assert!(service.webhooks_from_rest_enabled);
+        assert_eq!(service.reachability_webhooks_enabled, Some(true));
+        assert!(!service.acl_enabled);
+        assert!(service.reachability_debouncing_enabled);
+        assert_eq!(service.reachability_debouncing_window, 5000);
+        assert!(service.links.streams.ends_with("/Streams"));
+    }
+
+    #[test]
+    fn omitted_reachability_webhooks_is_none() {
+        let service: SyncService =
+            serde_json::from_str(r#"{"sid": "IS00000000000000000000000000000000"}"#).unwrap();
+
+        assert_eq!(service.reachability_webhooks_enabled, None);
+    }
+
+    #[tokio::test]
+    async fn list_paged_yields_pages_in_order() {
+        use futures::TryStreamExt;
+
+        let server = serve([MockResponse::json(r#"{"services":[{"sid":"IS1"},{"sid":"IS2"}],"meta":{"page":0,"page_size":2,"first_page_url":"https://sync.twilio.com/v1/Services?PageSize=2&Page=0","next_page_url":"https://sync.twilio.com/v1/Services?PageSize=2&Page=1","key":"services"}}"#), MockResponse::json(r#"{"services":[{"sid":"IS3"}],"meta":{"page":1,"page_size":2,"first_page_url":"https://sync.twilio.com/v1/Services?PageSize=2&Page=0","next_page_url":null,"key":"services"}}"#)]).await;
+        let client = Client::new(&test_config()).with_base_url(Domain::Sync, &server.url());
+
+        let pages = client
+            .sync()
+            .services()
+            .list_paged()
+            .map_ok(|page| {
+                page.items
+                    .into_iter()
+                    .map(|service| service.sid)
+                    .collect::<Vec<String>>()
+            })
+            .try_collect::<Vec<Vec<String>>>()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            pages,
+            vec![
+                vec![String::from("IS1"), String::from("IS2")],
+                vec![String::from("IS3")]
+            ]
+        );
+    }
+}
@@ -0,0 +1,311 @@
+/*!
+
+Contains Twilio Sync Stream related functionality.
+
+*/
+
+use crate::{Client, ErrorKind, ListPage, Page, PageMeta, TwilioError};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+/// Represents a page of Sync Streams from the Twilio API.
+#[allow(dead_code)]
+#[derive(Deserialize)]
+pub struct SyncStreamPage {
+    streams: Vec<SyncStream>,
+    meta: PageMeta,
+}
+
+impl Page for SyncStreamPage {
+    type Item = SyncStream;
+
+    fn into_list_page(self) -> ListPage<SyncStream> {
+        ListPage {
+            next_page_url: self.meta.next_page(),
+            items: self.streams,
+        }
+    }
+}
+
+/// A Sync Stream resource.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyncStream {
+    pub sid: String,
+    pub unique_name: String,
+    pub account_sid: String,
+    pub service_sid: String,
+    pub url: String,
+    pub date_created: String,
+    pub date_updated: String,
+    pub date_expires: Option<String>,
+    /// Identity of the creator. Uses the identity of the
+    /// respective client or defaults to `system` if created via REST.
+    pub created_by: String,
+    pub links: Links,
+}
+
+/// Resources _linked_ to a Sync Stream
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct Links {
+    pub stream_messages: String,
+}
+
+/// A message published to a Sync Stream. Messages aren't stored by Twilio so
+/// can't be fetched once published.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StreamMessage {
+    pub sid: String,
+    pub data: Value,
+}
+
+/// Parameters for creating a Sync Stream, e.g. `CreateParams::default().unique_name("example")`.
+#[skip_serializing_none]
+#[derive(Default, Serialize)]
+#[serde(rename_all(serialize = "PascalCase"))]
+pub struct CreateParams {
+    pub unique_name: Option<String>,
+    /// How long the Stream should exist before deletion (in seconds).
+    pub ttl: Option<u16>,
+}
+
+impl CreateParams {
+    pub fn unique_name(mut self, unique_name: impl Into<String>) -> Self {
+        self.unique_name = Some(unique_name.into());
+        self
+    }
+
+    pub fn ttl(mut self, ttl: u16) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+}
+
+/// Parameters for updating a Sync Stream
+#[skip_serializing_none]
+#[derive(Serialize)]
+#[serde(rename_all(serialize = "PascalCase"))]
+pub struct UpdateParams {
+    /// How long the Stream should exist before deletion (in seconds).
+    pub ttl: Option<u16>,
+}
+
+/// Parameters for publishing a Stream Message with data converted to a JSON string
+#[derive(Serialize)]
+#[serde(rename_all(serialize = "PascalCase"))]
+struct CreateMessageParams {
+    /// JSON string of data
+    data: String,
+}
+
+/// Maximum size of a Stream Message's data, in bytes, as JSON.
+const MAX_MESSAGE_SIZE: usize = 4096;
+
+pub struct Streams<'a, 'b> {
+    pub client: &'a Client,
+    pub service_sid: &'b str,
+}
+
+impl<'a, 'b> Streams<'a, 'b> {
+    /// [Creates a Sync Stream resource](https://www.twilio.com/docs/sync/api/stream-resource#create-a-sync-stream-resource)
+    ///
+    /// Creates a Sync Stream resource with the provided parameters.
+    pub async fn create(&self, params: CreateParams) -> Result<SyncStream, TwilioError> {
+        self.client
+            .send_request::<SyncStream, CreateParams>(
+                Method::POST,
+                &format!(
+                    "https://sync.twilio.com/v1/Services/{}/Streams",
+                    &self.service_sid
+                ),
+                Some(&params),
+                None,
+            )
+            .await
+    }
+
+    /// [Lists Sync Streams](https://www.twilio.com/docs/sync/api/stream-resource#read-multiple-sync-stream-resources)
+    ///
+    /// Lists Sync Streams existing on the Twilio account.
+    ///
+    /// Streams will be _eagerly_ paged until all retrieved. See `list_paged` to page lazily.
+    pub async fn list(&self) -> Result<Vec<SyncStream>, TwilioError> {
+        self.client.collect_pages(self.list_paged()).await
+    }
+
+    /// Lists streams a page at a time, see [`ListPage`].
+    pub fn list_paged(
+        &self,
+    ) -> impl futures::Stream<Item = Result<ListPage<SyncStream>, TwilioError>> + '_ {
+        self.client.list_pages::<SyncStreamPage, ()>(
+            format!(
+                "https://sync.twilio.com/v1/Services/{}/Streams?PageSize=50",
+                self.service_sid
+            ),
+            None,
+        )
+    }
+}
+
+pub struct Stream<'a, 'b> {
+    pub client: &'a Client,
+    pub service_sid: &'b str,
+    /// SID of the Sync Stream. Can also be it's unique name.
+    pub sid: &'b str,
+}
+
+impl<'a, 'b> Stream<'a, 'b> {
+    /// [Gets a Sync Stream](https://www.twilio.com/docs/sync/api/stream-resource#fetch-a-sync-stream-resource)
+    ///
+    /// Targets the Sync Service provided to the `service()` argument and fetches the Stream
+    /// provided to the `stream()` argument.
+    pub async fn get(&self) -> Result<SyncStream, TwilioError> {
+        self.client
+            .get_resource::<SyncStream>(&format!(
+                "https://sync.twilio.com/v1/Services/{}/Streams/{}",
+                self.service_sid, self.sid
+            ))
+            .await
+    }
+
+    /// [Update a Sync Stream](https://www.twilio.com/docs/sync/api/stream-resource#update-a-sync-stream-resource)
+    ///
+    /// Targets the Sync Service provided to the `service()` argument and updates the Stream
+    /// provided to the `stream()` argument.
+    pub async fn update(&self, params: UpdateParams) -> Result<SyncStream, TwilioError> {
+        self.client
+            .send_request::<SyncStream, UpdateParams>(
+                Method::POST,
+                &format!(
+                    "https://sync.twilio.com/v1/Services/{}/Streams/{}",
+                    self.service_sid, self.sid
+                ),
+                Some(&params),
+                None,
+            )
+            .await
+    }
+
+    /// [Deletes a Sync Stream](https://www.twilio.com/docs/sync/api/stream-resource#delete-a-sync-stream-resource)
+    ///
+    /// Targets the Sync Service provided to the `service()` argument and deletes the Stream
+    /// provided to the `stream()` argument.
+    pub async fn delete(&self) -> Result<(), TwilioError> {
+        self.client
+            .send_request_and_ignore_response::<()>(
+                Method::DELETE,
+                &format!(
+                    "https://sync.twilio.com/v1/Services/{}/Streams/{}",
+                    self.service_sid, self.sid
+                ),
+                None,
+                None,
+            )
+            .await
+    }
+
+    /// Stream Message functions for the Stream.
+    pub fn stream_messages(&'a self) -> StreamMessages {
+        StreamMessages {
+            client: self.client,
+            service_sid: self.service_sid,
+            stream_sid: self.sid,
+        }
+    }
+}
+
+pub struct StreamMessages<'a, 'b> {
+    pub client: &'a Client,
+    pub service_sid: &'b str,
+    pub stream_sid: &'b str,
+}
+
+impl<'a, 'b> StreamMessages<'a, 'b> {
+    /// [Publishes a Stream Message](https://www.twilio.com/docs/sync/api/stream-message-resource#create-a-stream-message-resource)
+    ///
+    /// Publishes `data`, any value that can be represented as JSON, to the subscribers of
+    /// the Stream. The data must be no larger than 4KiB as JSON.
+    pub async fn create<T>(&self, data: &T) -> Result<StreamMessage, TwilioError>
+    where
+        T: ?Sized + Serialize,
+    {
+        let params = CreateMessageParams {
+            data: serde_json::to_string(data)
+                .expect("Unable to convert provided data value to a JSON string"),
+        };
+
+        if params.data.len() > MAX_MESSAGE_SIZE {
+            return Err(TwilioError {
+                kind: ErrorKind::ValidationError(format!(
+                    "Stream Message data must be at most {} bytes. Was {}",
+                    MAX_MESSAGE_SIZE,
+                    params.data.len()
+                )),
+            });
+        }
+
+        self.client
+            .send_request::<StreamMessage, CreateMessageParams>(
+                Method::POST,
+                &format!(
+                    "https://sync.twilio.com/v1/Services/{}/Streams/{}/Messages",
+                    self.service_sid, self.stream_sid
+                ),
+                Some(&params),
+                None,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{serve, test_config, MockResponse};
+    use crate::{Client, Domain};
+
+    #[tokio::test]
+    async fn stream_created_and_message_published() {
+        let server = serve([MockResponse::json(r#"{"sid":"TO1","unique_name":"scores","service_sid":"IS1","links":{"stream_messages":"https://sync.twilio.com/v1/Services/IS1/Streams/TO1/Messages"}}"#).status(201), MockResponse::json(r#"{"sid":"TZ1","data":{"score":1}}"#).status(201)]).await;
+        let client = Client::new(&test_config()).with_base_url(Domain::Sync, &server.url());
+        let sync = client.sync();
+        let service = sync.service("IS1");
+
+        let stream = service
+            .streams()
+            .create(CreateParams::default().unique_name("scores"))
+            .await
+            .unwrap();
+        let message = service
+            .stream(&stream.sid)
+            .stream_messages()
+            .create(&serde_json::json!({ "score": 1 }))
+            .await
+            .unwrap();
+
+        assert_eq!(stream.unique_name, "scores");
+        assert_eq!(message.sid, "TZ1");
+        assert_eq!(message.data, serde_json::json!({ "score": 1 }));
+    }
+
+    #[tokio::test]
+    async fn oversized_stream_message_rejected() {
+        let client = Client::new(&test_config());
+        let sync = client.sync();
+        let service = sync.service("IS1");
+        let stream = service.stream("TO1");
+
+        let result = stream.stream_messages().create(&"a".repeat(5000)).await;
+
+        assert!(matches!(
+            result,
+            Err(TwilioError {
+                kind: ErrorKind::ValidationError(_)
+            })
+        ));
+    }
+}
@@ -0,0 +1,195 @@
+/*!
+
+Contains helpers shared across Twilio resources.
+
+*/
+
+use crate::{ErrorKind, TwilioError};
+
+/// Validates a phone number is in [E.164](https://www.twilio.com/docs/glossary/what-e164) format,
+/// a `+` followed by 1 to 15 digits e.g. `+447000000000`.
+pub fn validate_e164(number: &str) -> Result<(), TwilioError> {
+    let digits = match number.strip_prefix('+') {
+        Some(digits) => digits,
+        None => {
+            return Err(TwilioError {
+                kind: ErrorKind::ValidationError(format!(
+                    "Phone number '{}' must start with +",
+                    number
+                )),
+            })
+        }
+    };
+
+    if digits.is_empty() || digits.len() > 15 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(TwilioError {
+            kind: ErrorKind::ValidationError(format!(
+                "Phone number '{}' must be + followed by 1 to 15 digits",
+                number
+            )),
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates a duration is in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601#Durations)
+/// format, `P` followed by date components then optionally `T` and time components,
+/// e.g. `PT10M` or `P1DT12H`.
+pub fn validate_iso8601_duration(duration: &str) -> Result<(), TwilioError> {
+    let is_valid = match duration.strip_prefix('P') {
+        Some(components) => match components.split_once('T') {
+            Some((date, time)) => {
+                !time.is_empty()
+                    && has_valid_components(date, &['Y', 'M', 'W', 'D'])
+                    && has_valid_components(time, &['H', 'M', 'S'])
+            }
+            None => {
+                !components.is_empty() && has_valid_components(components, &['Y', 'M', 'W', 'D'])
+            }
+        },
+        None => false,
+    };
+
+    match is_valid {
+        true => Ok(()),
+        false => Err(TwilioError {
+            kind: ErrorKind::ValidationError(format!(
+                "Duration '{}' must be in ISO 8601 format, e.g. PT10M",
+                duration
+            )),
+        }),
+    }
+}
+
+/// Maximum size in bytes of the attributes or data Twilio stores against a resource, 16 KiB.
+pub const MAX_JSON_SIZE: usize = 16 * 1024;
+
+/// Validates `json`, the attributes or data of a resource named by `name`, is a JSON object
+/// of at most `MAX_JSON_SIZE` bytes as Twilio requires, e.g. `{"priority": "high"}`.
+pub fn validate_json_object(name: &str, json: &str) -> Result<(), TwilioError> {
+    if json.len() > MAX_JSON_SIZE {
+        return Err(TwilioError {
+            kind: ErrorKind::ValidationError(format!(
+                "{} must be at most {} bytes. Was {}",
+                name,
+                MAX_JSON_SIZE,
+                json.len()
+            )),
+        });
+    }
+
+    match serde_json::from_str::<serde_json::Value>(json) {
+        Ok(serde_json::Value::Object(_)) => Ok(()),
+        _ => Err(TwilioError {
+            kind: ErrorKind::ValidationError(format!(
+                "{} must be a JSON object, e.g. {{\"key\": \"value\"}}. Was {}",
+                name, json
+            )),
+        }),
+    }
+}
+
+// Whether `components` is a sequence of numbers each followed by one of `designators`,
+// in the order given and without repeats, e.g. `1Y2D`.
+fn has_valid_components(components: &str, designators: &[char]) -> bool {
+    let mut remaining = designators;
+    let mut digits = 0;
+    for c in components.chars() {
+        if c.is_ascii_digit() {
+            digits += 1;
+            continue;
+        }
+
+        match remaining.iter().position(|designator| *designator == c) {
+            Some(position) if digits > 0 => {
+                remaining = &remaining[position + 1..];
+                digits = 0;
+            }
+            _ => return false,
+        }
+    }
+
+    digits == 0
+}
+
+/// Checks `sid` is a Twilio SID for the resource identified by `prefix`, e.g. `CH` for
+/// Conversations. A SID is the two character prefix followed by 32 hexadecimal characters.
+/// Takes its arguments in the same order as `sid::validate_sid`.
+pub fn is_valid_sid(prefix: &str, sid: &str) -> bool {
+    match sid.strip_prefix(prefix) {
+        Some(id) => id.len() == 32 && id.chars().all(|c| c.is_ascii_hexdigit()),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iso8601_valid_durations() {
+        for duration in ["PT10M", "P1D", "P1DT12H", "PT1H30M15S", "P1Y2M3W4D"] {
+            assert!(validate_iso8601_duration(duration).is_ok(), "{}", duration);
+        }
+    }
+
+    #[test]
+    fn iso8601_invalid_durations() {
+        for duration in [
+            "", "P", "PT", "10M", "PT10", "PTM", "P1H", "PT1D", "PT1M1H", "P1DT",
+        ] {
+            assert!(validate_iso8601_duration(duration).is_err(), "{}", duration);
+        }
+    }
+
+    #[test]
+    fn e164_valid_numbers() {
+        assert!(validate_e164("+1").is_ok());
+        assert!(validate_e164("+447000000000").is_ok());
+        assert!(validate_e164("+123456789012345").is_ok());
+    }
+
+    #[test]
+    fn e164_invalid_numbers() {
+        assert!(validate_e164("").is_err());
+        assert!(validate_e164("+").is_err());
+        assert!(validate_e164("447000000000").is_err());
+        assert!(validate_e164("+44 7000 000000").is_err());
+        assert!(validate_e164("+1234567890123456").is_err());
+    }
+
+    #[test]
+    fn json_object_within_limit_valid() {
+        assert!(validate_json_object("Attributes", "{}").is_ok());
+        assert!(validate_json_object("Attributes", r#"{"priority":"high"}"#).is_ok());
+    }
+
+    #[test]
+    fn json_bare_scalars_rejected() {
+        for json in [r#""high""#, "1", "true", "null", "[1,2]", "not json"] {
+            assert!(
+                validate_json_object("Attributes", json).is_err(),
+                "{}",
+                json
+            );
+        }
+    }
+
+    #[test]
+    fn json_oversized_payload_rejected() {
+        let json = format!(r#"{{"notes":"{}"}}"#, "a".repeat(MAX_JSON_SIZE));
+        let error = validate_json_object("Data", &json).unwrap_err();
+        assert!(
+            matches!(error.kind, ErrorKind::ValidationError(message) if message.starts_with("Data must be at most 16384 bytes"))
+        );
+    }
+
+    #[test]
+    fn sid_prefix_and_length() {
+        assert!(is_valid_sid("CH", "CH0123456789abcdef0123456789abcdef"));
+        assert!(!is_valid_sid("ET", "CH0123456789abcdef0123456789abcdef"));
+        assert!(!is_valid_sid("CH", "CH0123456789abcdef"));
+        assert!(!is_valid_sid("CH", "CH0123456789abcdef0123456789abcdeg"));
+    }
+}
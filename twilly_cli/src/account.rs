@@ -1,16 +1,23 @@
-use std::{process, str::FromStr};
+use std::{fmt, process, str::FromStr};
 
 use inquire::{validator::Validation, Confirm, Select, Text};
+use serde::Serialize;
 use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter, EnumString};
-use twilly::{account::Status, Client};
+use twilly::{
+    account::{Account, CreateParams, Status},
+    Client, TwilioError,
+};
 use twilly_cli::{
-    get_action_choice_from_user, get_filter_choice_from_user, prompt_user, prompt_user_selection,
-    ActionChoice, FilterChoice,
+    dry_run, exit_with_error, get_action_choice_from_user, get_filter_choice_from_user,
+    print_dry_run, print_resource, prompt_user, prompt_user_selection, sid_validator, ActionChoice,
+    FilterChoice,
 };
 
 #[derive(Debug, Clone, Display, EnumIter, EnumString)]
 pub enum Action {
+    #[strum(to_string = "Who am I")]
+    WhoAmI,
     #[strum(to_string = "Get account")]
     GetAccount,
     #[strum(to_string = "List accounts")]
@@ -21,6 +28,70 @@ pub enum Action {
     Exit,
 }
 
+/// Actions changing the status of an account.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum StatusAction {
+    Activate,
+    Suspend,
+    Close,
+}
+
+impl StatusAction {
+    /// The status the account is updated to by the action.
+    fn status(&self) -> Status {
+        match self {
+            StatusAction::Activate => Status::Active,
+            StatusAction::Suspend => Status::Suspended,
+            StatusAction::Close => Status::Closed,
+        }
+    }
+}
+
+/// The account requests are being made against, shown so it can be checked before
+/// doing destructive work.
+#[derive(Debug, Serialize)]
+pub struct AccountSummary {
+    pub sid: String,
+    pub friendly_name: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub status: Status,
+    /// Balance with its currency, e.g. `10.52 USD`. `None` when it couldn't be fetched.
+    pub balance: Option<String>,
+}
+
+impl fmt::Display for AccountSummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}) - {} - {} - Balance: {}",
+            self.friendly_name,
+            self.sid,
+            self.type_field,
+            self.status,
+            self.balance.as_deref().unwrap_or("unavailable")
+        )
+    }
+}
+
+/// Fetches the authenticated account and its balance. The balance is left out rather
+/// than failing when it can't be fetched, e.g. for API keys without access to it.
+pub async fn fetch_account_summary(twilio: &Client) -> AccountSummary {
+    let accounts = twilio.accounts();
+    let (account, balance) = tokio::join!(accounts.get(None), accounts.balance(None));
+    let account = account.unwrap_or_else(|error| exit_with_error(&error));
+
+    AccountSummary {
+        sid: account.sid,
+        friendly_name: account.friendly_name,
+        type_field: account.type_field,
+        status: account.status,
+        balance: balance
+            .ok()
+            .map(|balance| format!("{} {}", balance.balance, balance.currency)),
+    }
+}
+
 pub async fn choose_account_action(twilio: &Client) {
     let options: Vec<Action> = Action::iter().collect();
 
@@ -29,30 +100,22 @@ pub async fn choose_account_action(twilio: &Client) {
 
         if let Some(action) = prompt_user_selection(action_selection_prompt) {
             match action {
+                Action::WhoAmI => {
+                    let account_summary = fetch_account_summary(twilio).await;
+                    print_resource(&account_summary);
+                }
                 Action::GetAccount => {
                     let account_sid_prompt = Text::new("Please provide an account SID:")
                         .with_placeholder("AC...")
-                        .with_validator(|val: &str| match val.starts_with("AC") {
-                            true => Ok(Validation::Valid),
-                            false => {
-                                Ok(Validation::Invalid("Account SID must start with AC".into()))
-                            }
-                        })
-                        .with_validator(|val: &str| match val.len() {
-                            34 => Ok(Validation::Valid),
-                            _ => Ok(Validation::Invalid(
-                                "Your SID should be 34 characters in length".into(),
-                            )),
-                        });
+                        .with_validator(sid_validator("AC"));
 
                     if let Some(account_sid) = prompt_user(account_sid_prompt) {
                         let account = twilio
                             .accounts()
                             .get(Some(&account_sid))
                             .await
-                            .unwrap_or_else(|error| panic!("{}", error));
-                        println!("{:#?}", account);
-                        println!();
+                            .unwrap_or_else(|error| exit_with_error(&error));
+                        print_resource(&account);
                     }
                 }
                 Action::CreateAccount => {
@@ -61,11 +124,16 @@ pub async fn choose_account_action(twilio: &Client) {
 
                     if let Some(friendly_name) = prompt_user(friendly_name_prompt) {
                         println!("Creating account...");
+                        let mut params = CreateParams::default();
+                        if !friendly_name.trim().is_empty() {
+                            params = params.friendly_name(friendly_name.trim());
+                        }
+
                         let account = twilio
                             .accounts()
-                            .create(Some(&friendly_name))
+                            .create(params)
                             .await
-                            .unwrap_or_else(|error| panic!("{}", error));
+                            .unwrap_or_else(|error| exit_with_error(&error));
                         println!(
                             "Account created: {} ({})",
                             account.friendly_name, account.sid
@@ -94,7 +162,7 @@ pub async fn choose_account_action(twilio: &Client) {
                                 .accounts()
                                 .list(Some(&friendly_name), status.as_ref())
                                 .await
-                                .unwrap_or_else(|error| panic!("{}", error));
+                                .unwrap_or_else(|error| exit_with_error(&error));
 
                             // The action we can perform on the account we are using are limited.
                             // Remove it from the list.
@@ -181,28 +249,34 @@ pub async fn choose_account_action(twilio: &Client) {
                                                             .clone_from(&friendly_name);
                                                         }
                                                         "Suspend" => {
-                                                            suspend_account(
+                                                            if suspend_account(
                                                                 twilio,
                                                                 &selected_account.sid,
                                                             )
-                                                            .await;
-                                                            accounts[selected_account_index
-                                                                .expect(
+                                                            .await
+                                                            {
+                                                                accounts[selected_account_index
+                                                                    .expect(
                                                                     "Selected account is unknown",
                                                                 )]
-                                                            .status = Status::Suspended;
+                                                                .status =
+                                                                    StatusAction::Suspend.status();
+                                                            }
                                                         }
                                                         "Close" => {
-                                                            close_account(
+                                                            if close_account(
                                                                 twilio,
                                                                 &selected_account.sid,
                                                             )
-                                                            .await;
-                                                            accounts[selected_account_index
-                                                                .expect(
+                                                            .await
+                                                            {
+                                                                accounts[selected_account_index
+                                                                    .expect(
                                                                     "Selected account is unknown",
                                                                 )]
-                                                            .status = Status::Closed;
+                                                                .status =
+                                                                    StatusAction::Close.status();
+                                                            }
                                                         }
                                                         _ => {
                                                             println!("Unknown action '{}'", choice);
@@ -240,16 +314,19 @@ pub async fn choose_account_action(twilio: &Client) {
                                                             .clone_from(&friendly_name);
                                                         }
                                                         "Activate" => {
-                                                            activate_account(
+                                                            if activate_account(
                                                                 twilio,
                                                                 &selected_account.sid,
                                                             )
-                                                            .await;
-                                                            accounts[selected_account_index
-                                                                .expect(
+                                                            .await
+                                                            {
+                                                                accounts[selected_account_index
+                                                                    .expect(
                                                                     "Selected account is unknown",
                                                                 )]
-                                                            .status = Status::Active;
+                                                                .status =
+                                                                    StatusAction::Activate.status();
+                                                            }
                                                         }
 
                                                         _ => {
@@ -303,14 +380,14 @@ async fn change_account_name(twilio: &Client, account_sid: &str) {
             .accounts()
             .update(account_sid, Some(&friendly_name), None)
             .await
-            .unwrap_or_else(|error| panic!("{}", error));
+            .unwrap_or_else(|error| exit_with_error(&error));
 
-        println!("{:#?}", updated_account);
-        println!();
+        print_resource(&updated_account);
     }
 }
 
-async fn activate_account(twilio: &Client, account_sid: &str) {
+/// Returns whether the account was activated, i.e. the request was sent.
+async fn activate_account(twilio: &Client, account_sid: &str) -> bool {
     let confirmation_prompt = Confirm::new("Are you sure you wish to activate this account?")
         .with_placeholder("N")
         .with_default(false);
@@ -318,21 +395,34 @@ async fn activate_account(twilio: &Client, account_sid: &str) {
     if let Some(confirmation) = prompt_user(confirmation_prompt) {
         if confirmation {
             println!("Activating account...");
-            twilio
-                .accounts()
-                .update(account_sid, None, Some(&Status::Suspended))
+            update_account_status(twilio, account_sid, StatusAction::Activate)
                 .await
-                .unwrap_or_else(|error| panic!("{}", error));
+                .unwrap_or_else(|error| exit_with_error(&error));
 
             println!("Account activated.");
-            return;
+            return true;
         }
     }
 
     println!("Operation canceled. No changes were made.");
+    false
 }
 
-async fn suspend_account(twilio: &Client, account_sid: &str) {
+/// Returns whether the account was suspended, i.e. the request was sent. A dry run sends
+/// nothing.
+async fn suspend_account(twilio: &Client, account_sid: &str) -> bool {
+    if dry_run() {
+        print_dry_run(
+            "POST",
+            &[account_url(account_sid)],
+            Some(&format!(
+                "Status={}",
+                StatusAction::Suspend.status().as_str()
+            )),
+        );
+        return false;
+    }
+
     let confirmation_prompt = Confirm::new(
         "Are you sure you wish to suspend this account? Any activity will be disabled until the account is re-activated."
     )
@@ -342,22 +432,32 @@ async fn suspend_account(twilio: &Client, account_sid: &str) {
     if let Some(confirmation) = prompt_user(confirmation_prompt) {
         if confirmation {
             println!("Suspending account...");
-            let res = twilio
-                .accounts()
-                .update(account_sid, None, Some(&Status::Suspended))
+            let res = update_account_status(twilio, account_sid, StatusAction::Suspend)
                 .await
-                .unwrap_or_else(|error| panic!("{}", error));
+                .unwrap_or_else(|error| exit_with_error(&error));
 
             println!("{}", res);
             println!("Account suspended.");
-            return;
+            return true;
         }
     }
 
     println!("Operation canceled. No changes were made.");
+    false
 }
 
-async fn close_account(twilio: &Client, account_sid: &str) {
+/// Returns whether the account was closed, i.e. the request was sent. A dry run sends
+/// nothing.
+async fn close_account(twilio: &Client, account_sid: &str) -> bool {
+    if dry_run() {
+        print_dry_run(
+            "POST",
+            &[account_url(account_sid)],
+            Some(&format!("Status={}", StatusAction::Close.status().as_str())),
+        );
+        return false;
+    }
+
     let confirmation_prompt = Confirm::new(
         "Are you sure you wish to Close this account? Activity will be disabled and this action cannot be reversed."
     )
@@ -367,18 +467,71 @@ async fn close_account(twilio: &Client, account_sid: &str) {
     if let Some(confirmation) = prompt_user(confirmation_prompt) {
         if confirmation {
             println!("Closing account...");
-            twilio
-                .accounts()
-                .update(account_sid, None, Some(&Status::Suspended))
+            update_account_status(twilio, account_sid, StatusAction::Close)
                 .await
-                .unwrap_or_else(|error| panic!("{}", error));
+                .unwrap_or_else(|error| exit_with_error(&error));
 
             println!(
                 "Account closed. This account will still be visible in the console for 30 days."
             );
-            return;
+            return true;
         }
     }
 
     println!("Operation canceled. No changes were made.");
+    false
+}
+
+/// Updates the status of the account to the one set by `action`.
+async fn update_account_status(
+    twilio: &Client,
+    account_sid: &str,
+    action: StatusAction,
+) -> Result<Account, TwilioError> {
+    twilio
+        .accounts()
+        .update(account_sid, None, Some(&action.status()))
+        .await
+}
+
+/// URL of the account with the SID provided.
+fn account_url(account_sid: &str) -> String {
+    format!(
+        "https://api.twilio.com/2010-04-01/Accounts/{}.json",
+        account_sid
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use twilly::{
+        mock::{serve, MockResponse},
+        Domain,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn status_sent_matches_action() {
+        for (action, expected) in [
+            (StatusAction::Activate, "Status=active"),
+            (StatusAction::Suspend, "Status=suspended"),
+            (StatusAction::Close, "Status=closed"),
+        ] {
+            let server = serve([MockResponse::json("{}")]).await;
+
+            update_account_status(
+                &server.client(Domain::Api),
+                "AC00000000000000000000000000000000",
+                action,
+            )
+            .await
+            .unwrap();
+
+            let request = server.request();
+            assert!(request
+                .starts_with("POST /2010-04-01/Accounts/AC00000000000000000000000000000000.json "));
+            assert!(request.ends_with(expected), "{:?}", action);
+        }
+    }
 }
@@ -0,0 +1,311 @@
+use clap::{Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use strum::IntoEnumIterator;
+use twilly::{conversation::State, Client};
+
+use twilly_cli::{
+    exit_with_error, export::parse_export_path, print_list, print_resource, OutputMode,
+};
+
+use crate::account::fetch_account_summary;
+
+/// Command line arguments. Without a command the interactive menus are shown.
+#[derive(Parser)]
+#[command(name = "twilly", version, about)]
+pub struct Cli {
+    /// Disable emoji and colour.
+    #[arg(long, global = true)]
+    pub plain: bool,
+    /// Print every request as an equivalent cURL command on stderr.
+    #[arg(long, global = true)]
+    pub curl: bool,
+    /// Include the auth token in printed cURL commands.
+    #[arg(long, global = true, requires = "curl")]
+    pub insecure_show_secrets: bool,
+    /// Twilio region requests are processed in, e.g. `ie1`.
+    #[arg(long, global = true)]
+    pub region: Option<String>,
+    /// Twilio edge location requests are routed through, e.g. `dublin`.
+    #[arg(long, global = true)]
+    pub edge: Option<String>,
+    /// Print the requests destructive operations would send without sending them.
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+    /// How fetched resources are printed: `text` or `json`.
+    #[arg(long, global = true, default_value = "text")]
+    pub output: OutputMode,
+    /// Write the resources fetched by a list command to a file rather than printing
+    /// them, as JSON or CSV based on its extension, e.g. `accounts.csv`.
+    #[arg(long, global = true, value_parser = parse_export_path)]
+    pub export: Option<PathBuf>,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Show the account requests are made against, with its type, status and balance.
+    Whoami,
+    /// Twilio accounts.
+    #[command(subcommand)]
+    Accounts(AccountCommand),
+    /// Conversations of the default Conversation Service.
+    #[command(subcommand)]
+    Conversations(ConversationCommand),
+    /// Sync Services and their resources.
+    #[command(subcommand)]
+    Sync(SyncCommand),
+    /// Serverless Services and their Environments.
+    #[command(subcommand)]
+    Serverless(ServerlessCommand),
+}
+
+#[derive(Subcommand)]
+pub enum AccountCommand {
+    /// Get an account, defaulting to the authenticated account.
+    Get { sid: Option<String> },
+    /// List the account and its sub-accounts.
+    List {
+        #[arg(long)]
+        friendly_name: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ConversationCommand {
+    /// Get a Conversation.
+    Get { sid: String },
+    /// List Conversations.
+    List {
+        /// Only list Conversations in this state: active, inactive or closed.
+        #[arg(long, value_parser = parse_state)]
+        state: Option<State>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum SyncCommand {
+    /// Sync Services.
+    #[command(subcommand)]
+    Services(ServiceCommand),
+    /// Sync Documents of a Service.
+    #[command(subcommand)]
+    Documents(ServiceResourceCommand),
+    /// Sync Lists of a Service.
+    #[command(subcommand)]
+    Lists(ServiceResourceCommand),
+    /// Sync Maps of a Service.
+    #[command(subcommand)]
+    Maps(ServiceResourceCommand),
+}
+
+#[derive(Subcommand)]
+pub enum ServerlessCommand {
+    /// Serverless Services.
+    #[command(subcommand)]
+    Services(ServiceCommand),
+    /// Environments of a Serverless Service.
+    #[command(subcommand)]
+    Environments(ServiceResourceCommand),
+}
+
+/// Commands for top level Services.
+#[derive(Subcommand)]
+pub enum ServiceCommand {
+    /// Get a Service.
+    Get { sid: String },
+    /// List Services.
+    List,
+}
+
+/// Commands for resources belonging to a Service.
+#[derive(Subcommand)]
+pub enum ServiceResourceCommand {
+    /// Get a resource of the Service.
+    Get {
+        /// SID of the Service the resource belongs to.
+        #[arg(long)]
+        service: String,
+        sid: String,
+    },
+    /// List resources of the Service.
+    List {
+        /// SID of the Service the resources belong to.
+        #[arg(long)]
+        service: String,
+    },
+}
+
+/// Parses a Conversation state, ignoring case.
+fn parse_state(value: &str) -> Result<State, String> {
+    State::iter()
+        .find(|state| state.as_ref().eq_ignore_ascii_case(value))
+        .ok_or_else(|| {
+            format!(
+                "Unknown state '{}'. Expected active, inactive or closed",
+                value
+            )
+        })
+}
+
+/// Runs a single command, printing the result, without prompting. Listed resources are
+/// written to `export` instead when given. Exits on failure.
+pub async fn run(twilio: &Client, command: Command, export: Option<&Path>) {
+    match command {
+        Command::Whoami => print_resource(&fetch_account_summary(twilio).await),
+        Command::Accounts(command) => match command {
+            AccountCommand::Get { sid } => {
+                let account = twilio
+                    .accounts()
+                    .get(sid.as_deref())
+                    .await
+                    .unwrap_or_else(|error| exit_with_error(&error));
+                print_resource(&account);
+            }
+            AccountCommand::List { friendly_name } => {
+                let accounts = twilio
+                    .accounts()
+                    .list(friendly_name.as_deref(), None)
+                    .await
+                    .unwrap_or_else(|error| exit_with_error(&error));
+                print_list(&accounts, export);
+            }
+        },
+        Command::Conversations(command) => match command {
+            ConversationCommand::Get { sid } => {
+                let conversation = twilio
+                    .conversations()
+                    .get(&sid)
+                    .await
+                    .unwrap_or_else(|error| exit_with_error(&error));
+                print_resource(&conversation);
+            }
+            ConversationCommand::List { state } => {
+                let conversations = twilio
+                    .conversations()
+                    .list(None, None, state, None)
+                    .await
+                    .unwrap_or_else(|error| exit_with_error(&error));
+                print_list(&conversations, export);
+            }
+        },
+        Command::Sync(command) => run_sync(twilio, command, export).await,
+        Command::Serverless(command) => run_serverless(twilio, command, export).await,
+    }
+}
+
+async fn run_sync(twilio: &Client, command: SyncCommand, export: Option<&Path>) {
+    let sync = twilio.sync();
+    match command {
+        SyncCommand::Services(ServiceCommand::Get { sid }) => {
+            let service = sync
+                .service(&sid)
+                .get()
+                .await
+                .unwrap_or_else(|error| exit_with_error(&error));
+            print_resource(&service);
+        }
+        SyncCommand::Services(ServiceCommand::List) => {
+            let services = sync
+                .services()
+                .list()
+                .await
+                .unwrap_or_else(|error| exit_with_error(&error));
+            print_list(&services, export);
+        }
+        SyncCommand::Documents(ServiceResourceCommand::Get { service, sid }) => {
+            let document = sync
+                .service(&service)
+                .document(&sid)
+                .get()
+                .await
+                .unwrap_or_else(|error| exit_with_error(&error));
+            print_resource(&document);
+        }
+        SyncCommand::Documents(ServiceResourceCommand::List { service }) => {
+            let documents = sync
+                .service(&service)
+                .documents()
+                .list()
+                .await
+                .unwrap_or_else(|error| exit_with_error(&error));
+            print_list(&documents, export);
+        }
+        SyncCommand::Lists(ServiceResourceCommand::Get { service, sid }) => {
+            let list = sync
+                .service(&service)
+                .list(&sid)
+                .get()
+                .await
+                .unwrap_or_else(|error| exit_with_error(&error));
+            print_resource(&list);
+        }
+        SyncCommand::Lists(ServiceResourceCommand::List { service }) => {
+            let lists = sync
+                .service(&service)
+                .lists()
+                .list()
+                .await
+                .unwrap_or_else(|error| exit_with_error(&error));
+            print_list(&lists, export);
+        }
+        SyncCommand::Maps(ServiceResourceCommand::Get { service, sid }) => {
+            let map = sync
+                .service(&service)
+                .map(&sid)
+                .get()
+                .await
+                .unwrap_or_else(|error| exit_with_error(&error));
+            print_resource(&map);
+        }
+        SyncCommand::Maps(ServiceResourceCommand::List { service }) => {
+            let maps = sync
+                .service(&service)
+                .maps()
+                .list()
+                .await
+                .unwrap_or_else(|error| exit_with_error(&error));
+            print_list(&maps, export);
+        }
+    }
+}
+
+async fn run_serverless(twilio: &Client, command: ServerlessCommand, export: Option<&Path>) {
+    let serverless = twilio.serverless();
+    match command {
+        ServerlessCommand::Services(ServiceCommand::Get { sid }) => {
+            let service = serverless
+                .service(&sid)
+                .get()
+                .await
+                .unwrap_or_else(|error| exit_with_error(&error));
+            print_resource(&service);
+        }
+        ServerlessCommand::Services(ServiceCommand::List) => {
+            let services = serverless
+                .services()
+                .list()
+                .await
+                .unwrap_or_else(|error| exit_with_error(&error));
+            print_list(&services, export);
+        }
+        ServerlessCommand::Environments(ServiceResourceCommand::Get { service, sid }) => {
+            let environment = serverless
+                .service(&service)
+                .environment(&sid)
+                .get()
+                .await
+                .unwrap_or_else(|error| exit_with_error(&error));
+            print_resource(&environment);
+        }
+        ServerlessCommand::Environments(ServiceResourceCommand::List { service }) => {
+            let environments = serverless
+                .service(&service)
+                .environments()
+                .list()
+                .await
+                .unwrap_or_else(|error| exit_with_error(&error));
+            print_list(&environments, export);
+        }
+    }
+}
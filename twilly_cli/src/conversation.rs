@@ -1,16 +1,22 @@
 use chrono::Datelike;
-use std::{process, str::FromStr};
+use std::{fs::File, io::Write, process, str::FromStr};
 
 use inquire::{validator::Validation, Confirm, Select, Text};
 use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter, EnumString};
 use twilly::{
-    conversation::{Conversation, State, UpdateConversation},
-    Client, ErrorKind,
+    conversation::{Conversation, ListOrder, State, UpdateConversation},
+    participant_conversation::filter_by_state,
+    sid::ConversationSid,
+    util::validate_e164,
+    Client, ErrorKind, TwilioError,
 };
 use twilly_cli::{
-    get_action_choice_from_user, get_date_from_user, get_filter_choice_from_user, prompt_user,
-    prompt_user_selection, ActionChoice, DateRange, FilterChoice,
+    concurrency, confirm_bulk_operation, dry_run, exit_with_error, get_action_choice_from_user,
+    get_date_from_user, get_filter_choice_from_user, last_modified_suffix, load_profile,
+    print_bulk_summary, print_dry_run, print_resource, prompt_user, prompt_user_selection,
+    sid_or_unique_name_validator, sort_by_last_modified, store_profile, ActionChoice, DateRange,
+    FilterChoice,
 };
 
 #[derive(Clone, Display, EnumIter, EnumString)]
@@ -21,6 +27,10 @@ pub enum Action {
     ListConversations,
     #[strum(to_string = "List Conversations by identifier")]
     ListByIdentifier,
+    #[strum(to_string = "Find active Conversation by participant")]
+    FindByParticipant,
+    #[strum(to_string = "Sync Conversations since last run")]
+    SyncConversations,
     #[strum(to_string = "Close Conversation")]
     CloseConversation,
     #[strum(to_string = "Close all Conversations")]
@@ -44,16 +54,8 @@ pub async fn choose_conversation_action(twilio: &Client) {
                 Action::GetConversation => {
                     let conversation_sid_prompt =
                         Text::new("Please provide a conversation SID, or unique name:")
-                            .with_placeholder("CH...")
-                            .with_validator(|val: &str| {
-                                if val.starts_with("CH") && val.len() == 34 {
-                                    Ok(Validation::Valid)
-                                } else {
-                                    Ok(Validation::Invalid(
-                                        "Conversation SID should be 34 characters in length".into(),
-                                    ))
-                                }
-                            });
+                            .with_placeholder("CH... or unique name")
+                            .with_validator(sid_or_unique_name_validator("CH"));
 
                     if let Some(conversation_sid) = prompt_user(conversation_sid_prompt) {
                         match twilio.conversations().get(&conversation_sid).await {
@@ -72,8 +74,7 @@ pub async fn choose_conversation_action(twilio: &Client) {
                                         ActionChoice::Exit => process::exit(0),
                                         ActionChoice::Other(choice) => match choice.as_str() {
                                             "List Details" => {
-                                                println!("{:#?}", conversation);
-                                                println!();
+                                                print_resource(&conversation);
                                             }
                                             "Delete" => {
                                                 let confirm_prompt = Confirm::new(
@@ -89,7 +90,7 @@ pub async fn choose_conversation_action(twilio: &Client) {
                                                         .delete(&conversation_sid)
                                                         .await
                                                         .unwrap_or_else(|error| {
-                                                            panic!("{}", error)
+                                                            exit_with_error(&error)
                                                         });
                                                     println!("Conversation deleted.");
                                                     println!();
@@ -111,10 +112,12 @@ pub async fn choose_conversation_action(twilio: &Client) {
                                         );
                                         println!();
                                     } else {
-                                        panic!("{}", twilio_error);
+                                        exit_with_error(&TwilioError {
+                                            kind: ErrorKind::TwilioError(twilio_error),
+                                        });
                                     }
                                 }
-                                _ => panic!("{}", error),
+                                _ => exit_with_error(&error),
                             },
                         }
                     }
@@ -188,12 +191,23 @@ pub async fn choose_conversation_action(twilio: &Client) {
                                 }
                             };
 
+                            // Applied to the fetched conversations as Twilio can't filter by name.
+                            let name_filter_prompt = Text::new(
+                                "Filter by friendly or unique name containing? (empty for none):",
+                            );
+                            let name_filter = prompt_user(name_filter_prompt).unwrap_or_default();
+
                             println!("Fetching conversations...");
                             let mut conversations = twilio
                                 .conversations()
-                                .list(start_date, end_date, state)
+                                .list(start_date, end_date, state, None)
                                 .await
-                                .unwrap_or_else(|error| panic!("{}", error));
+                                .unwrap_or_else(|error| exit_with_error(&error));
+                            conversations
+                                .retain(|conversation| name_contains(conversation, &name_filter));
+                            sort_by_last_modified(&mut conversations, |conversation| {
+                                &conversation.date_updated
+                            });
 
                             let number_of_conversations = conversations.len();
 
@@ -216,13 +230,25 @@ pub async fn choose_conversation_action(twilio: &Client) {
                                     } else if let Some(action_choice) = get_action_choice_from_user(
                                         conversations
                                             .iter()
-                                            .map(|conv| match &conv.unique_name {
-                                                Some(unique_name) => format!(
-                                                    "({}) {} - {}",
-                                                    conv.sid, unique_name, conv.state
-                                                ),
-                                                None => {
-                                                    format!("{} - {}", conv.sid, conv.state)
+                                            .map(|conv| {
+                                                match conv
+                                                    .unique_name
+                                                    .as_ref()
+                                                    .or(conv.friendly_name.as_ref())
+                                                {
+                                                    Some(name) => format!(
+                                                        "({}) {} - {}{}",
+                                                        conv.sid,
+                                                        name,
+                                                        conv.state,
+                                                        last_modified_suffix(&conv.date_updated)
+                                                    ),
+                                                    None => format!(
+                                                        "{} - {}{}",
+                                                        conv.sid,
+                                                        conv.state,
+                                                        last_modified_suffix(&conv.date_updated)
+                                                    ),
                                                 }
                                             })
                                             .collect::<Vec<String>>(),
@@ -236,7 +262,11 @@ pub async fn choose_conversation_action(twilio: &Client) {
                                             ActionChoice::Other(choice) => {
                                                 let conversation_position = conversations
                                                     .iter()
-                                                    .position(|conv| conv.sid == choice[..34])
+                                                    .position(|conv| {
+                                                        choice
+                                                            .trim_start_matches('(')
+                                                            .starts_with(&conv.sid)
+                                                    })
                                                     .expect(
                                                         "Could not find conversation in existing conversation list"
                                                     );
@@ -256,6 +286,7 @@ pub async fn choose_conversation_action(twilio: &Client) {
                                                 get_action_choice_from_user(
                                                     vec![
                                                         String::from("List details"),
+                                                        String::from("View attributes"),
                                                         String::from("Delete"),
                                                     ],
                                                     "Select an action: ",
@@ -271,11 +302,12 @@ pub async fn choose_conversation_action(twilio: &Client) {
                                                         .as_str()
                                                     {
                                                         "List details" => {
-                                                            println!(
-                                                                "{:#?}",
-                                                                selected_conversation
+                                                            print_resource(&selected_conversation);
+                                                        }
+                                                        "View attributes" => {
+                                                            print_conversation_attributes(
+                                                                selected_conversation,
                                                             );
-                                                            println!();
                                                         }
                                                         "Delete" => {
                                                             delete_conversation(
@@ -306,6 +338,7 @@ pub async fn choose_conversation_action(twilio: &Client) {
                                                 get_action_choice_from_user(
                                                     vec![
                                                         String::from("List details"),
+                                                        String::from("View attributes"),
                                                         String::from("Re-activate"),
                                                         String::from("Delete"),
                                                     ],
@@ -322,11 +355,12 @@ pub async fn choose_conversation_action(twilio: &Client) {
                                                         .as_str()
                                                     {
                                                         "List details" => {
-                                                            println!(
-                                                                "{:#?}",
-                                                                selected_conversation
+                                                            print_resource(&selected_conversation);
+                                                        }
+                                                        "View attributes" => {
+                                                            print_conversation_attributes(
+                                                                selected_conversation,
                                                             );
-                                                            println!();
                                                         }
                                                         "Re-activate" => {
                                                             let updated_conversation =
@@ -337,6 +371,7 @@ pub async fn choose_conversation_action(twilio: &Client) {
                                                                         state: Some(State::Active),
                                                                         friendly_name: None,
                                                                         unique_name: None,
+                                                                        messaging_service_sid: None,
                                                                         attributes: None,
                                                                         timers: None,
                                                                     },
@@ -378,6 +413,7 @@ pub async fn choose_conversation_action(twilio: &Client) {
                                                 get_action_choice_from_user(
                                                     vec![
                                                         String::from("List details"),
+                                                        String::from("View attributes"),
                                                         String::from("De-activate"),
                                                         String::from("Delete"),
                                                     ],
@@ -394,11 +430,12 @@ pub async fn choose_conversation_action(twilio: &Client) {
                                                         .as_str()
                                                     {
                                                         "List details" => {
-                                                            println!(
-                                                                "{:#?}",
-                                                                selected_conversation
+                                                            print_resource(&selected_conversation);
+                                                        }
+                                                        "View attributes" => {
+                                                            print_conversation_attributes(
+                                                                selected_conversation,
                                                             );
-                                                            println!();
                                                         }
                                                         "De-activate" => {
                                                             let updated_conversation =
@@ -411,6 +448,7 @@ pub async fn choose_conversation_action(twilio: &Client) {
                                                                         ),
                                                                         friendly_name: None,
                                                                         unique_name: None,
+                                                                        messaging_service_sid: None,
                                                                         attributes: None,
                                                                         timers: None,
                                                                     },
@@ -451,34 +489,9 @@ pub async fn choose_conversation_action(twilio: &Client) {
                     }
                 }
                 Action::ListByIdentifier => {
-                    let mut identity: Option<String> = None;
-                    let mut address: Option<String> = None;
-
-                    let identifier_selection =
-                        Select::new("Select an identifier:", vec!["Identity", "Address"])
-                            .with_help_message("Identity for chat-based users otherwise Address");
-
-                    if let Some(identifier) = prompt_user_selection(identifier_selection) {
-                        match identifier {
-                            "Identity" => {
-                                let identity_prompt =
-                                    Text::new("Please provide the identity to search for:");
-
-                                identity = prompt_user(identity_prompt);
-                            }
-                            "Address" => {
-                                let address_prompt =
-                                    Text::new("Please provide the address to search for:");
-
-                                address = prompt_user(address_prompt);
-                            }
-                            _ => {
-                                println!("Unknown identifier '{}'", identifier)
-                            }
-                        }
-                    } else {
-                        break;
-                    }
+                    let Some((identity, address)) = get_participant_identifier_from_user() else {
+                        continue;
+                    };
 
                     if let Some(filter_choice) = get_filter_choice_from_user(
                         State::iter().map(|state| state.to_string()).collect(),
@@ -493,20 +506,15 @@ pub async fn choose_conversation_action(twilio: &Client) {
                         let participant_conversations = twilio
                             .conversations()
                             .participant_conversations()
-                            .list(identity, address)
+                            .list(identity, address, None)
                             .await
-                            .unwrap_or_else(|error| panic!("{}", error));
+                            .unwrap_or_else(|error| exit_with_error(&error));
 
                         // The Participant Conversations endpoint doesn't support state filtering so we need
                         // to fetch all then filter here.
                         let filtered_conversations = match state {
                             None => participant_conversations,
-                            Some(state) => participant_conversations
-                                .into_iter()
-                                .filter(|participant_conv| {
-                                    participant_conv.conversation_state == state
-                                })
-                                .collect(),
+                            Some(state) => filter_by_state(participant_conversations, &state),
                         };
 
                         let number_of_conversations = filtered_conversations.len();
@@ -526,19 +534,56 @@ pub async fn choose_conversation_action(twilio: &Client) {
                         }
                     }
                 }
+                Action::FindByParticipant => {
+                    if let Some((identity, address)) = get_participant_identifier_from_user() {
+                        println!("Fetching conversations...");
+                        let participant_conversations = twilio
+                            .conversations()
+                            .participant_conversations()
+                            .list(identity, address, None)
+                            .await
+                            .unwrap_or_else(|error| exit_with_error(&error));
+                        let active_conversations =
+                            filter_by_state(participant_conversations, &State::Active);
+
+                        match active_conversations.len() {
+                            0 => {
+                                println!("No active conversations found for the participant.");
+                                println!();
+                            }
+                            1 => {
+                                let conversation = twilio
+                                    .conversations()
+                                    .get(&active_conversations[0].conversation_sid)
+                                    .await
+                                    .unwrap_or_else(|error| exit_with_error(&error));
+                                println!("Conversation found.");
+                                println!();
+                                choose_participant_conversation_action(twilio, &conversation).await;
+                            }
+                            number_of_conversations => {
+                                println!(
+                                    "Found {} active conversations for the participant.",
+                                    number_of_conversations
+                                );
+                                println!();
+                                active_conversations.into_iter().for_each(|conv| {
+                                    println!(
+                                        "{} - {}",
+                                        conv.conversation_sid, conv.conversation_date_created
+                                    )
+                                });
+                                println!();
+                            }
+                        }
+                    }
+                }
+                Action::SyncConversations => sync_conversations(twilio).await,
                 Action::CloseConversation => {
                     let conversation_sid_prompt =
                         Text::new("Please provide a conversation SID, or unique name:")
-                            .with_placeholder("CH...")
-                            .with_validator(|val: &str| {
-                                if val.starts_with("CH") && val.len() == 34 {
-                                    Ok(Validation::Valid)
-                                } else {
-                                    Ok(Validation::Invalid(
-                                        "Conversation SID should be 34 characters in length".into(),
-                                    ))
-                                }
-                            });
+                            .with_placeholder("CH... or unique name")
+                            .with_validator(sid_or_unique_name_validator("CH"));
 
                     if let Some(conversation_sid) = prompt_user(conversation_sid_prompt) {
                         close_conversation(twilio, &conversation_sid).await;
@@ -564,53 +609,47 @@ pub async fn choose_conversation_action(twilio: &Client) {
 
                     let conversations = twilio
                         .conversations()
-                        .list(None, None, Some(State::Active))
+                        .list(None, None, Some(State::Active), None)
                         .await
-                        .unwrap_or_else(|error| panic!("{}", error));
+                        .unwrap_or_else(|error| exit_with_error(&error));
 
                     println!(
                         "We've found {} active conversations to close.",
                         conversations.len()
                     );
-                    let count_confirmation_prompt = Confirm::new("Continue?")
-                        .with_default(false)
-                        .with_placeholder("N");
 
-                    let count_confirmation_result = prompt_user(count_confirmation_prompt);
-
-                    if count_confirmation_result.is_none() {
+                    if dry_run() {
+                        let urls = conversations
+                            .iter()
+                            .map(|conversation| conversation.url.clone())
+                            .collect::<Vec<String>>();
+                        print_dry_run("POST", &urls, Some("State=closed"));
                         return;
                     }
 
-                    if let Some(false) = count_confirmation_result {
+                    if !confirm_bulk_operation(conversations.len(), "close") {
+                        println!("Operation canceled. No changes were made.");
+                        println!();
                         return;
                     }
 
                     println!("Proceeding with closing. Please wait...");
-                    for conversation in conversations {
-                        close_conversation(twilio, &conversation.sid).await;
-                        // This is not particularly smart but this prevents overwhelming Twilio.
-                        // Close 1 Conversation per second. The rate could be much higher than this.
-                        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
-                    }
-
-                    println!("All active conversations closed.");
-                    println!();
+                    let sids = conversations
+                        .into_iter()
+                        .filter_map(|conversation| ConversationSid::new(conversation.sid).ok())
+                        .collect::<Vec<ConversationSid>>();
+                    let results = twilio
+                        .conversations()
+                        .close_many(&sids, concurrency())
+                        .await;
+                    print_bulk_summary(&results, "Closed", "Conversations");
                     return;
                 }
                 Action::DeleteConversation => {
                     let conversation_sid_prompt =
                         Text::new("Please provide a conversation SID, or unique name:")
-                            .with_placeholder("CH...")
-                            .with_validator(|val: &str| {
-                                if val.starts_with("CH") && val.len() == 34 {
-                                    Ok(Validation::Valid)
-                                } else {
-                                    Ok(Validation::Invalid(
-                                        "Conversation SID should be 34 characters in length".into(),
-                                    ))
-                                }
-                            });
+                            .with_placeholder("CH... or unique name")
+                            .with_validator(sid_or_unique_name_validator("CH"));
 
                     if let Some(conversation_sid) = prompt_user(conversation_sid_prompt) {
                         delete_conversation(twilio, &conversation_sid).await;
@@ -619,45 +658,46 @@ pub async fn choose_conversation_action(twilio: &Client) {
                     }
                 }
                 Action::DeleteAllConversations => {
-                    let first_confirmation_prompt =
+                    let confirmation_prompt =
                         Confirm::new("Are you sure you wish to delete **all** Conversations?")
                             .with_placeholder("N")
                             .with_default(false);
-                    let second_confirmation_prompt =
-                        Confirm::new("Are you double sure? There is no going back.")
-                            .with_placeholder("N")
-                            .with_default(false);
 
-                    if let Some(first_confirmation) = prompt_user(first_confirmation_prompt) {
-                        if first_confirmation {
-                            if let Some(second_confirmation) =
-                                prompt_user(second_confirmation_prompt)
-                            {
-                                if second_confirmation {
-                                    println!("Proceeding with deletion. Please wait...");
-                                    let conversations = twilio
-                                        .conversations()
-                                        .list(None, None, None)
-                                        .await
-                                        .unwrap_or_else(|error| panic!("{}", error));
-
-                                    for conversation in conversations {
-                                        twilio
-                                            .conversations()
-                                            .delete(&conversation.sid)
-                                            .await
-                                            .unwrap_or_else(|error| panic!("{}", error));
-                                        // This is not particularly smart but this prevents overwhelming Twilio.
-                                        // Delete 1 Conversation per second. The rate could be much higher than this.
-                                        tokio::time::sleep(tokio::time::Duration::from_secs(1))
-                                            .await;
-                                    }
+                    if let Some(true) = prompt_user(confirmation_prompt) {
+                        let conversations = twilio
+                            .conversations()
+                            .list(None, None, None, None)
+                            .await
+                            .unwrap_or_else(|error| exit_with_error(&error));
+
+                        println!(
+                            "We've found {} conversations to delete.",
+                            conversations.len()
+                        );
+
+                        if dry_run() {
+                            let urls = conversations
+                                .iter()
+                                .map(|conversation| conversation.url.clone())
+                                .collect::<Vec<String>>();
+                            print_dry_run("DELETE", &urls, None);
+                            return;
+                        }
 
-                                    println!("All conversations deleted.");
-                                    println!();
-                                    return;
-                                }
-                            }
+                        if confirm_bulk_operation(conversations.len(), "delete") {
+                            println!("Proceeding with deletion. Please wait...");
+                            let sids = conversations
+                                .into_iter()
+                                .filter_map(|conversation| {
+                                    ConversationSid::new(conversation.sid).ok()
+                                })
+                                .collect::<Vec<ConversationSid>>();
+                            let results = twilio
+                                .conversations()
+                                .delete_many(&sids, concurrency())
+                                .await;
+                            print_bulk_summary(&results, "Deleted", "Conversations");
+                            return;
                         }
                     }
 
@@ -675,6 +715,158 @@ pub async fn choose_conversation_action(twilio: &Client) {
     }
 }
 
+/// Prompts the user to choose between searching by identity or address and
+/// then for the value itself. Returns `(identity, address)` with at most one
+/// populated, or `None` if the user canceled the identifier selection.
+fn get_participant_identifier_from_user() -> Option<(Option<String>, Option<String>)> {
+    let mut identity: Option<String> = None;
+    let mut address: Option<String> = None;
+
+    let identifier_selection = Select::new("Select an identifier:", vec!["Identity", "Address"])
+        .with_help_message("Identity for chat-based users otherwise Address");
+
+    let identifier = prompt_user_selection(identifier_selection)?;
+    match identifier {
+        "Identity" => {
+            let identity_prompt = Text::new("Please provide the identity to search for:");
+
+            identity = prompt_user(identity_prompt);
+        }
+        "Address" => {
+            let address_prompt = Text::new("Please provide the address to search for:")
+                .with_placeholder("+44...")
+                .with_validator(|val: &str| match is_valid_participant_address(val) {
+                    true => Ok(Validation::Valid),
+                    false => Ok(Validation::Invalid(
+                        "Address should be a phone number in E.164 format e.g. +447000000000, or \
+                         channel:address e.g. whatsapp:+447000000000 or messenger:1234567890"
+                            .into(),
+                    )),
+                });
+
+            address = prompt_user(address_prompt);
+        }
+        _ => {
+            println!("Unknown identifier '{}'", identifier)
+        }
+    }
+
+    Some((identity, address))
+}
+
+// Whether `address` is an SMS number in E.164 format or a `channel:address` binding, e.g.
+// `whatsapp:+447000000000` or `messenger:1234567890`. WhatsApp carries an E.164 number after
+// its prefix whereas other channels use their own identifiers.
+fn is_valid_participant_address(address: &str) -> bool {
+    match address.split_once(':') {
+        None => validate_e164(address).is_ok(),
+        Some(("whatsapp", number)) => validate_e164(number).is_ok(),
+        Some((channel, id)) => {
+            !channel.is_empty()
+                && channel.chars().all(|c| c.is_ascii_alphanumeric())
+                && !id.trim().is_empty()
+        }
+    }
+}
+
+/// Action menu for a single active conversation found through one of its
+/// participants. Returns once the conversation is closed or deleted, or the
+/// user goes back.
+async fn choose_participant_conversation_action(twilio: &Client, conversation: &Conversation) {
+    while let Some(conversation_action) = get_action_choice_from_user(
+        vec![
+            String::from("List details"),
+            String::from("View attributes"),
+            String::from("Close"),
+            String::from("Delete"),
+        ],
+        "Select an action: ",
+    ) {
+        match conversation_action {
+            ActionChoice::Back => break,
+            ActionChoice::Exit => process::exit(0),
+            ActionChoice::Other(choice) => match choice.as_str() {
+                "List details" => {
+                    print_resource(&conversation);
+                }
+                "View attributes" => print_conversation_attributes(conversation),
+                "Close" => {
+                    close_conversation(twilio, &conversation.sid).await;
+                    break;
+                }
+                "Delete" => {
+                    delete_conversation(twilio, &conversation.sid).await;
+                    break;
+                }
+                _ => println!("Unknown action '{}'", choice),
+            },
+        }
+    }
+}
+
+/// Writes Conversations updated since the last sync to a file, recording the time of
+/// this sync in the profile. The first sync writes all Conversations.
+async fn sync_conversations(twilio: &Client) {
+    let mut profile = load_profile();
+    let last_sync = profile
+        .last_conversation_sync
+        .as_ref()
+        .and_then(|last_sync| chrono::DateTime::parse_from_rfc3339(last_sync).ok())
+        .map(|last_sync| last_sync.with_timezone(&chrono::Utc));
+
+    // Taken before fetching so Conversations updated during the sync are picked up next time.
+    let sync_started = chrono::Utc::now();
+
+    let conversations = match last_sync {
+        Some(last_sync) => {
+            println!("Fetching conversations updated since {}...", last_sync);
+            twilio
+                .conversations()
+                .list_updated_after(last_sync, Some(ListOrder::DateCreated))
+                .await
+        }
+        None => {
+            println!("No previous sync found. Fetching all conversations...");
+            twilio
+                .conversations()
+                .list(None, None, None, Some(ListOrder::DateCreated))
+                .await
+        }
+    }
+    .unwrap_or_else(|error| exit_with_error(&error));
+
+    let file_name = format!(
+        "conversations-{}.json",
+        sync_started.format("%Y%m%dT%H%M%SZ")
+    );
+    match File::create(&file_name) {
+        Ok(mut file_buffer) => {
+            match file_buffer.write_all(
+                serde_json::to_string_pretty(&conversations)
+                    .unwrap()
+                    .as_bytes(),
+            ) {
+                Ok(_) => {
+                    println!(
+                        "Synced {} conversations to {}.",
+                        conversations.len(),
+                        file_name
+                    );
+                    println!();
+
+                    profile.last_conversation_sync = Some(sync_started.to_rfc3339());
+                    store_profile(&profile);
+                }
+                Err(error) => eprintln!(
+                    "Failed to fully write to sync file. Action aborted: {}",
+                    error
+                ),
+            }
+        }
+        Err(error) => eprintln!("Unable to create sync file. Action aborted: {}", error),
+    }
+}
+
 /// Prompts the user for confirmation before deleting the conversation with
 /// the SID provided. Will panic if the delete operation fails.
 async fn update_conversation(
@@ -689,8 +881,33 @@ async fn update_conversation(
 
             updated_conversation
         }
-        Err(error) => panic!("{}", error),
+        Err(error) => exit_with_error(&error),
+    }
+}
+
+/// Prints the attributes of a conversation as formatted JSON. Falls back to
+/// the raw string if the attributes are not valid JSON.
+fn print_conversation_attributes(conversation: &Conversation) {
+    match conversation.parsed_attributes() {
+        Ok(attributes) => println!(
+            "{}",
+            serde_json::to_string_pretty(&attributes)
+                .unwrap_or_else(|_| conversation.attributes.to_string())
+        ),
+        Err(_) => println!("{}", conversation.attributes),
     }
+    println!();
+}
+
+/// Whether the friendly or unique name of `conversation` contains `filter`, ignoring case.
+/// Every conversation matches an empty filter.
+fn name_contains(conversation: &Conversation, filter: &str) -> bool {
+    let filter = filter.trim().to_lowercase();
+    filter.is_empty()
+        || [&conversation.friendly_name, &conversation.unique_name]
+            .into_iter()
+            .flatten()
+            .any(|name| name.to_lowercase().contains(&filter))
 }
 
 /// Helper function to encapsulate a conversation close update
@@ -701,6 +918,7 @@ async fn close_conversation(twilio: &Client, sid: &str) {
             sid,
             UpdateConversation {
                 unique_name: None,
+                messaging_service_sid: None,
                 friendly_name: None,
                 state: Some(State::Closed),
                 attributes: None,
@@ -714,7 +932,7 @@ async fn close_conversation(twilio: &Client, sid: &str) {
             println!();
         }
         Err(error) => {
-            panic!("{}", error);
+            exit_with_error(&error);
         }
     }
 }
@@ -739,10 +957,12 @@ async fn delete_conversation(twilio: &Client, sid: &str) {
                             println!("A Conversation with SID '{}' was not found.", &sid);
                             println!();
                         } else {
-                            panic!("{}", twilio_error)
+                            exit_with_error(&TwilioError {
+                                kind: ErrorKind::TwilioError(twilio_error),
+                            })
                         }
                     }
-                    _ => panic!("{}", error),
+                    _ => exit_with_error(&error),
                 },
             }
         }
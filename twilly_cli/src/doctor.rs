@@ -0,0 +1,65 @@
+use std::time::{Duration, Instant};
+
+use twilly::{Client, TwilioError};
+use twilly_cli::symbol;
+
+/// Runs a sequence of read-only requests against Twilio reporting the outcome
+/// and duration of each. Confirms credentials, network access and permissions
+/// are in order without changing anything on the account.
+pub async fn run_checks(twilio: &Client) {
+    println!("Running checks...");
+    println!();
+
+    let mut failed_checks = 0;
+
+    let started = Instant::now();
+    let result = twilio
+        .verify_credentials()
+        .await
+        .map(|account| format!("{} ({})", account.friendly_name, account.status));
+    failed_checks += report_check("Verify credentials", result, started.elapsed());
+
+    let started = Instant::now();
+    let result = twilio
+        .sync()
+        .services()
+        .list()
+        .await
+        .map(|services| format!("{} Sync Services", services.len()));
+    failed_checks += report_check("List Sync Services", result, started.elapsed());
+
+    println!();
+    if failed_checks == 0 {
+        println!("All checks passed.");
+    } else {
+        println!("{} check(s) failed.", failed_checks);
+    }
+    println!();
+}
+
+// Prints the outcome of a single check. Returns `1` if the check failed
+// otherwise `0` so the caller can tally failures.
+fn report_check(name: &str, result: Result<String, TwilioError>, elapsed: Duration) -> usize {
+    match result {
+        Ok(detail) => {
+            println!(
+                "{} {} - {} [{}ms]",
+                symbol("✅", "[OK]"),
+                name,
+                detail,
+                elapsed.as_millis()
+            );
+            0
+        }
+        Err(error) => {
+            println!(
+                "{} {} - {} [{}ms]",
+                symbol("❌", "[FAIL]"),
+                name,
+                error,
+                elapsed.as_millis()
+            );
+            1
+        }
+    }
+}
@@ -0,0 +1,340 @@
+/*!
+
+Writing fetched resources to files and the columns each resource is shown with.
+
+*/
+
+use std::{
+    fs::File,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use serde::Serialize;
+use strum_macros::{Display, EnumIter};
+use twilly::{
+    account::Account,
+    conversation::Conversation,
+    serverless::{
+        environments::{logs::ServerlessLog, ServerlessEnvironment},
+        services::ServerlessService,
+    },
+    sync::{documents::SyncDocument, lists::SyncList, maps::SyncMap, services::SyncService},
+};
+
+/// The most relevant fields of a resource, shown as table columns and written as CSV
+/// columns.
+pub trait Columns {
+    /// Header of each column shown in tables.
+    const HEADERS: &'static [&'static str];
+
+    /// Name of the field in each column, used as the CSV header, e.g. `date_created`.
+    const FIELDS: &'static [&'static str];
+
+    /// The value of each column for the resource, in the order of `HEADERS`.
+    fn row(&self) -> Vec<String>;
+}
+
+/// Formats resources can be exported in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumIter)]
+pub enum ExportFormat {
+    #[strum(to_string = "JSON")]
+    Json,
+    #[strum(to_string = "CSV")]
+    Csv,
+}
+
+impl ExportFormat {
+    /// File extension of the format, without the leading `.`.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Csv => "csv",
+        }
+    }
+
+    /// The format matching the extension of `path`, `.json` or `.csv`.
+    pub fn from_path(path: &Path) -> Option<ExportFormat> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "json" => Some(ExportFormat::Json),
+            "csv" => Some(ExportFormat::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// Parses an `--export` path, accepting only paths ending in `.json` or `.csv`.
+pub fn parse_export_path(value: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(value);
+    match ExportFormat::from_path(&path) {
+        Some(_) => Ok(path),
+        None => Err(format!(
+            "Unsupported export file '{}'. Expected a .json or .csv file",
+            value
+        )),
+    }
+}
+
+/// Writes `resources` to the file at `path` in the format of its extension, as pretty
+/// printed JSON or as CSV with the `Columns` of the resource.
+pub fn export<T: Serialize + Columns>(path: &Path, resources: &[T]) -> io::Result<()> {
+    let format = ExportFormat::from_path(path).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, "Expected a .json or .csv file")
+    })?;
+
+    export_as(path, format, resources)
+}
+
+/// Writes `resources` to the file at `path` in `format`, regardless of its extension.
+pub fn export_as<T: Serialize + Columns>(
+    path: &Path,
+    format: ExportFormat,
+    resources: &[T],
+) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    match format {
+        ExportFormat::Json => {
+            serde_json::to_writer_pretty(&mut file, resources)?;
+            writeln!(file)
+        }
+        ExportFormat::Csv => {
+            writeln!(file, "{}", csv_record(T::FIELDS.iter().copied()))?;
+            for resource in resources {
+                writeln!(
+                    file,
+                    "{}",
+                    csv_record(resource.row().iter().map(String::as_str))
+                )?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Joins `fields` into a CSV record. Fields containing commas, quotes or line breaks are
+/// quoted with any quotes doubled.
+pub fn csv_record<'a>(fields: impl Iterator<Item = &'a str>) -> String {
+    fields
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+impl Columns for Account {
+    const HEADERS: &'static [&'static str] =
+        &["SID", "Friendly name", "Status", "Created", "Updated"];
+    const FIELDS: &'static [&'static str] = &[
+        "sid",
+        "friendly_name",
+        "status",
+        "date_created",
+        "date_updated",
+    ];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.sid.clone(),
+            self.friendly_name.clone(),
+            self.status.to_string(),
+            self.date_created.clone(),
+            self.date_updated.clone(),
+        ]
+    }
+}
+
+impl Columns for Conversation {
+    const HEADERS: &'static [&'static str] = &["SID", "Name", "State", "Created", "Updated"];
+    const FIELDS: &'static [&'static str] =
+        &["sid", "name", "state", "date_created", "date_updated"];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.sid.clone(),
+            self.unique_name
+                .clone()
+                .or_else(|| self.friendly_name.clone())
+                .unwrap_or_default(),
+            self.state.to_string(),
+            self.date_created.clone(),
+            self.date_updated.clone(),
+        ]
+    }
+}
+
+impl Columns for SyncService {
+    const HEADERS: &'static [&'static str] =
+        &["SID", "Unique name", "Friendly name", "Created", "Updated"];
+    const FIELDS: &'static [&'static str] = &[
+        "sid",
+        "unique_name",
+        "friendly_name",
+        "date_created",
+        "date_updated",
+    ];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.sid.clone(),
+            self.unique_name.clone().unwrap_or_default(),
+            self.friendly_name.clone().unwrap_or_default(),
+            self.date_created.clone(),
+            self.date_updated.clone(),
+        ]
+    }
+}
+
+impl Columns for SyncDocument {
+    const HEADERS: &'static [&'static str] =
+        &["SID", "Unique name", "Created", "Updated", "Expires"];
+    const FIELDS: &'static [&'static str] = &[
+        "sid",
+        "unique_name",
+        "date_created",
+        "date_updated",
+        "date_expires",
+    ];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.sid.clone(),
+            self.unique_name.clone(),
+            self.date_created.clone(),
+            self.date_updated.clone(),
+            self.date_expires.clone().unwrap_or_default(),
+        ]
+    }
+}
+
+impl Columns for SyncList {
+    const HEADERS: &'static [&'static str] =
+        &["SID", "Unique name", "Created", "Updated", "Expires"];
+    const FIELDS: &'static [&'static str] = &[
+        "sid",
+        "unique_name",
+        "date_created",
+        "date_updated",
+        "date_expires",
+    ];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.sid.clone(),
+            self.unique_name.clone(),
+            self.date_created.clone(),
+            self.date_updated.clone(),
+            self.date_expires.clone().unwrap_or_default(),
+        ]
+    }
+}
+
+impl Columns for SyncMap {
+    const HEADERS: &'static [&'static str] =
+        &["SID", "Unique name", "Created", "Updated", "Expires"];
+    const FIELDS: &'static [&'static str] = &[
+        "sid",
+        "unique_name",
+        "date_created",
+        "date_updated",
+        "date_expires",
+    ];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.sid.clone(),
+            self.unique_name.clone(),
+            self.date_created.clone(),
+            self.date_updated.clone(),
+            self.date_expires.clone().unwrap_or_default(),
+        ]
+    }
+}
+
+impl Columns for ServerlessService {
+    const HEADERS: &'static [&'static str] =
+        &["SID", "Unique name", "Friendly name", "Created", "Updated"];
+    const FIELDS: &'static [&'static str] = &[
+        "sid",
+        "unique_name",
+        "friendly_name",
+        "date_created",
+        "date_updated",
+    ];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.sid.clone(),
+            self.unique_name.clone(),
+            self.friendly_name.clone(),
+            self.date_created.clone(),
+            self.date_updated.clone(),
+        ]
+    }
+}
+
+impl Columns for ServerlessEnvironment {
+    const HEADERS: &'static [&'static str] =
+        &["SID", "Unique name", "Domain", "Created", "Updated"];
+    const FIELDS: &'static [&'static str] = &[
+        "sid",
+        "unique_name",
+        "domain_name",
+        "date_created",
+        "date_updated",
+    ];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.sid.clone(),
+            self.unique_name.clone(),
+            self.domain_name.clone(),
+            self.date_created.clone(),
+            self.date_updated.clone(),
+        ]
+    }
+}
+
+impl Columns for ServerlessLog {
+    const HEADERS: &'static [&'static str] =
+        &["SID", "Created", "Level", "Message", "Function SID"];
+    const FIELDS: &'static [&'static str] =
+        &["sid", "date_created", "level", "message", "function_sid"];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.sid.clone(),
+            self.date_created.clone(),
+            self.level.to_string(),
+            self.message.clone(),
+            self.function_sid.clone(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_fields_quoted_when_needed() {
+        let record = csv_record(
+            [
+                "IM1",
+                "Hello, world",
+                "She said \"hi\"",
+                "First line\nSecond line",
+            ]
+            .into_iter(),
+        );
+
+        assert_eq!(
+            record,
+            "IM1,\"Hello, world\",\"She said \"\"hi\"\"\",\"First line\nSecond line\""
+        );
+    }
+}
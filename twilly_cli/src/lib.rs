@@ -16,48 +16,645 @@ the functionality of the crate.
 - Additional _helpers_ not found in the default Twilio CLI.
 
 */
-use std::{fmt::Display, process};
+pub mod export;
+
+use std::{
+    cmp::Reverse,
+    fmt::Display,
+    io::{self, Write},
+    path::Path,
+    process,
+    sync::OnceLock,
+};
 
 use chrono::Datelike;
 use chrono::NaiveDate;
+use comfy_table::{presets, Attribute, Cell, Color, ContentArrangement, Table};
+use export::Columns;
 use inquire::MultiSelect;
 use inquire::{
-    validator::Validation, Confirm, DateSelect, InquireError, Password, PasswordDisplayMode,
-    Select, Text,
+    validator::{StringValidator, Validation},
+    Confirm, DateSelect, InquireError, Password, PasswordDisplayMode, Select, Text,
+};
+use serde::{Deserialize, Serialize};
+use twilly::{
+    sid::validate_sid, ErrorKind, ListProgress, TwilioConfig, TwilioError, ACCOUNT_SID_ENV,
+    API_KEY_ENV, API_SECRET_ENV, AUTH_TOKEN_ENV,
 };
-use twilly::TwilioConfig;
+
+/// Exit code on success.
+pub const EXIT_SUCCESS: i32 = 0;
+/// Exit code for an error without a more specific code, e.g. a network failure.
+pub const EXIT_ERROR: i32 = 1;
+/// Exit code when arguments were invalid, either locally or as reported by Twilio.
+pub const EXIT_VALIDATION: i32 = 2;
+/// Exit code when Twilio rejected the credentials or their permissions.
+pub const EXIT_AUTHENTICATION: i32 = 3;
+/// Exit code when Twilio was unavailable, i.e. it rate limited requests, failed with a
+/// server error or retries were exhausted.
+pub const EXIT_RATE_LIMITED: i32 = 4;
+/// Exit code when the user interrupted the program, e.g. Ctrl + C.
+pub const EXIT_INTERRUPTED: i32 = 130;
+
+/// Number of options shown at once by selection prompts unless configured
+/// otherwise in the profile.
+pub const DEFAULT_PAGE_SIZE: usize = 15;
+
+/// Number of requests bulk operations send at once unless configured otherwise
+/// in the profile.
+pub const DEFAULT_CONCURRENCY: usize = 5;
+
+/// Number of items fetched at once by interactive lists. More are fetched on
+/// choosing `LOAD_MORE_OPTION`.
+pub const DEFAULT_LIST_LIMIT: usize = 200;
+
+/// Bounds of the concurrency accepted from the profile.
+pub const MIN_CONCURRENCY: usize = 1;
+pub const MAX_CONCURRENCY: usize = 50;
+
+/// Bounds of the requests per second accepted from the profile. Requests are
+/// limited to `twilly::DEFAULT_REQUESTS_PER_SECOND` unless configured otherwise.
+pub const MIN_REQUESTS_PER_SECOND: u32 = 1;
+pub const MAX_REQUESTS_PER_SECOND: u32 = twilly::DEFAULT_REQUESTS_PER_SECOND;
+
+/// Help text shown on selection prompts that don't provide their own.
+const SELECT_HELP_MESSAGE: &str = "↑↓ to move, type to filter, enter to select, esc to go back";
+
+/// Help text shown on multi-selection prompts that don't provide their own.
+const MULTI_SELECT_HELP_MESSAGE: &str =
+    "↑↓ to move, space to toggle, → to select all, ← to select none, type to filter, enter to confirm, esc to go back";
+
+/// `SELECT_HELP_MESSAGE` in ASCII, shown when plain output is enabled.
+const PLAIN_SELECT_HELP_MESSAGE: &str =
+    "up/down to move, type to filter, enter to select, esc to go back";
+
+/// `MULTI_SELECT_HELP_MESSAGE` in ASCII, shown when plain output is enabled.
+const PLAIN_MULTI_SELECT_HELP_MESSAGE: &str =
+    "up/down to move, space to toggle, right to select all, left to select none, type to filter, enter to confirm, esc to go back";
+
+/// Option offered alongside a partially loaded list to fetch the next page.
+pub const LOAD_MORE_OPTION: &str = "Load more...";
+
+static PAGE_SIZE: OnceLock<usize> = OnceLock::new();
+
+static CONCURRENCY: OnceLock<usize> = OnceLock::new();
+
+static REQUESTS_PER_SECOND: OnceLock<u32> = OnceLock::new();
+
+static PLAIN_OUTPUT: OnceLock<bool> = OnceLock::new();
+
+static SORT_BY_LAST_MODIFIED: OnceLock<bool> = OnceLock::new();
+
+static OUTPUT_MODE: OnceLock<OutputMode> = OnceLock::new();
+
+static DRY_RUN: OnceLock<bool> = OnceLock::new();
+
+static BULK_CONFIRMATION_THRESHOLD: OnceLock<usize> = OnceLock::new();
+
+/// How fetched resources are printed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Human readable debug output.
+    #[default]
+    Text,
+    /// Pretty printed JSON, suitable for piping into other tools.
+    Json,
+}
+
+impl std::str::FromStr for OutputMode {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "text" => Ok(OutputMode::Text),
+            "json" => Ok(OutputMode::Json),
+            other => Err(format!(
+                "Unknown output mode '{}'. Expected 'text' or 'json'",
+                other
+            )),
+        }
+    }
+}
+
+/// The CLI profile persisted between sessions. Holds the Twilio credentials
+/// along with CLI preferences.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Profile {
+    #[serde(flatten)]
+    pub config: TwilioConfig,
+    /// Number of options shown at once by selection prompts.
+    #[serde(default = "default_page_size")]
+    pub page_size: usize,
+    /// Number of requests bulk operations send at once, between 1 and 50.
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
+    /// Maximum number of requests sent to Twilio each second, including retries,
+    /// between 1 and 100.
+    #[serde(default = "default_requests_per_second")]
+    pub requests_per_second: u32,
+    /// Whether list menus show the most recently modified resources first, along
+    /// with how long ago each was modified.
+    #[serde(default)]
+    pub sort_by_last_modified: bool,
+    /// Number of resources above which a bulk operation requires the user to type
+    /// a confirmation rather than answer yes or no.
+    #[serde(default = "default_bulk_confirmation_threshold")]
+    pub bulk_confirmation_threshold: usize,
+    /// When Conversations were last synced (RFC 3339). Only Conversations updated
+    /// since are fetched on the next sync.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_conversation_sync: Option<String>,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self {
+            config: TwilioConfig::default(),
+            page_size: DEFAULT_PAGE_SIZE,
+            concurrency: DEFAULT_CONCURRENCY,
+            requests_per_second: twilly::DEFAULT_REQUESTS_PER_SECOND,
+            sort_by_last_modified: false,
+            bulk_confirmation_threshold: DEFAULT_BULK_CONFIRMATION_THRESHOLD,
+            last_conversation_sync: None,
+        }
+    }
+}
+
+/// Loads the profile persisted between sessions. The default profile is
+/// returned if it cannot be read. A corrupt profile can be reset by the user.
+pub fn load_profile() -> Profile {
+    match confy::load::<Profile>("twilly", "profile") {
+        Ok(profile) => profile,
+        Err(error) => {
+            eprintln!(
+                "Unable to load profile configuration from {}: {}",
+                profile_path(),
+                error
+            );
+
+            if let confy::ConfyError::BadTomlData(_) = error {
+                let reset_prompt = Confirm::new("The profile appears to be corrupt. Reset it?")
+                    .with_placeholder("N")
+                    .with_default(false);
+
+                if let Some(true) = prompt_user(reset_prompt) {
+                    store_profile(&Profile::default());
+                }
+            }
+
+            Profile {
+                ..Default::default()
+            }
+        }
+    }
+}
+
+/// Persists the profile for future sessions. On failure the user may retry or
+/// continue without saving.
+///
+/// Returns `true` if the profile was stored.
+pub fn store_profile(profile: &Profile) -> bool {
+    loop {
+        match confy::store("twilly", "profile", profile) {
+            Ok(_) => return true,
+            Err(error) => {
+                eprintln!(
+                    "Unable to store profile configuration to {}: {}",
+                    profile_path(),
+                    error
+                );
+
+                let retry_prompt = Select::new(
+                    "How would you like to proceed?",
+                    vec!["Retry", "Continue without saving"],
+                );
+                match prompt_user_selection(retry_prompt) {
+                    Some("Retry") => continue,
+                    _ => {
+                        println!("Continuing without saving the profile.");
+                        return false;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Location of the profile file for use in messages.
+fn profile_path() -> String {
+    match confy::get_configuration_file_path("twilly", "profile") {
+        Ok(path) => path.display().to_string(),
+        Err(_) => String::from("(unknown location)"),
+    }
+}
+
+fn default_page_size() -> usize {
+    DEFAULT_PAGE_SIZE
+}
+
+/// Sets the number of options shown at once by selection prompts. Only the
+/// first call has an effect, subsequent calls are ignored.
+pub fn set_page_size(page_size: usize) {
+    let _ = PAGE_SIZE.set(page_size.max(1));
+}
+
+fn page_size() -> usize {
+    *PAGE_SIZE.get().unwrap_or(&DEFAULT_PAGE_SIZE)
+}
+
+fn default_concurrency() -> usize {
+    DEFAULT_CONCURRENCY
+}
+
+/// Sets the number of requests bulk operations send at once. Values outside of
+/// `MIN_CONCURRENCY` and `MAX_CONCURRENCY` are clamped with a warning. Only the
+/// first call has an effect, subsequent calls are ignored.
+pub fn set_concurrency(concurrency: usize) {
+    let clamped_concurrency = concurrency.clamp(MIN_CONCURRENCY, MAX_CONCURRENCY);
+    if clamped_concurrency != concurrency {
+        eprintln!(
+            "Profile concurrency of {} is outside of {}-{}. Using {}.",
+            concurrency, MIN_CONCURRENCY, MAX_CONCURRENCY, clamped_concurrency
+        );
+    }
+
+    let _ = CONCURRENCY.set(clamped_concurrency);
+}
+
+/// Number of requests bulk operations should send at once.
+pub fn concurrency() -> usize {
+    *CONCURRENCY.get().unwrap_or(&DEFAULT_CONCURRENCY)
+}
+
+fn default_requests_per_second() -> u32 {
+    twilly::DEFAULT_REQUESTS_PER_SECOND
+}
+
+/// Sets the maximum number of requests sent to Twilio each second. Values outside
+/// of `MIN_REQUESTS_PER_SECOND` and `MAX_REQUESTS_PER_SECOND` are clamped with a
+/// warning. Only the first call has an effect, subsequent calls are ignored.
+pub fn set_requests_per_second(requests_per_second: u32) {
+    let clamped_requests_per_second =
+        requests_per_second.clamp(MIN_REQUESTS_PER_SECOND, MAX_REQUESTS_PER_SECOND);
+    if clamped_requests_per_second != requests_per_second {
+        eprintln!(
+            "Profile requests per second of {} is outside of {}-{}. Using {}.",
+            requests_per_second,
+            MIN_REQUESTS_PER_SECOND,
+            MAX_REQUESTS_PER_SECOND,
+            clamped_requests_per_second
+        );
+    }
+
+    let _ = REQUESTS_PER_SECOND.set(clamped_requests_per_second);
+}
+
+/// Maximum number of requests the client should send each second.
+pub fn requests_per_second() -> u32 {
+    *REQUESTS_PER_SECOND
+        .get()
+        .unwrap_or(&twilly::DEFAULT_REQUESTS_PER_SECOND)
+}
+
+fn default_bulk_confirmation_threshold() -> usize {
+    DEFAULT_BULK_CONFIRMATION_THRESHOLD
+}
+
+/// Sets the number of resources above which bulk operations require a typed
+/// confirmation. Only the first call has an effect, subsequent calls are ignored.
+pub fn set_bulk_confirmation_threshold(bulk_confirmation_threshold: usize) {
+    let _ = BULK_CONFIRMATION_THRESHOLD.set(bulk_confirmation_threshold);
+}
+
+fn bulk_confirmation_threshold() -> usize {
+    *BULK_CONFIRMATION_THRESHOLD
+        .get()
+        .unwrap_or(&DEFAULT_BULK_CONFIRMATION_THRESHOLD)
+}
+
+/// Enables or disables sorting list menus by last modified. Only the first call
+/// has an effect, subsequent calls are ignored.
+pub fn set_sort_by_last_modified(sort_by_last_modified: bool) {
+    let _ = SORT_BY_LAST_MODIFIED.set(sort_by_last_modified);
+}
+
+fn sort_by_last_modified_enabled() -> bool {
+    *SORT_BY_LAST_MODIFIED.get().unwrap_or(&false)
+}
+
+/// Sorts `items` most recently modified first when enabled in the profile, otherwise
+/// `items` are left in the order Twilio returned them. Items with a `date_updated`
+/// that can't be parsed are placed last.
+pub fn sort_by_last_modified<T>(items: &mut [T], date_updated: impl Fn(&T) -> &str) {
+    if sort_by_last_modified_enabled() {
+        items.sort_by_key(|item| Reverse(parse_date(date_updated(item))));
+    }
+}
+
+/// Describes how long ago `date_updated` was, e.g. ` - 2h ago`, for appending to
+/// a list menu entry. Empty unless sorting by last modified is enabled in the profile.
+pub fn last_modified_suffix(date_updated: &str) -> String {
+    if !sort_by_last_modified_enabled() {
+        return String::new();
+    }
+
+    match parse_date(date_updated) {
+        Some(date_updated) => format!(" - {}", relative_time(date_updated)),
+        None => String::new(),
+    }
+}
+
+// Parses a Twilio timestamp. Most APIs use ISO8601 whereas the Accounts API uses RFC 2822.
+fn parse_date(date: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(date)
+        .or_else(|_| chrono::DateTime::parse_from_rfc2822(date))
+        .ok()
+        .map(|date| date.with_timezone(&chrono::Utc))
+}
+
+// Describes how long ago `date` was in the largest whole unit, e.g. `2h ago`.
+fn relative_time(date: chrono::DateTime<chrono::Utc>) -> String {
+    let elapsed = chrono::Utc::now() - date;
+
+    if elapsed.num_minutes() < 1 {
+        String::from("just now")
+    } else if elapsed.num_hours() < 1 {
+        format!("{}m ago", elapsed.num_minutes())
+    } else if elapsed.num_days() < 1 {
+        format!("{}h ago", elapsed.num_hours())
+    } else {
+        format!("{}d ago", elapsed.num_days())
+    }
+}
+
+/// Enables or disables plain output, free of emoji and colour, for logs and
+/// redirection. Only the first call has an effect, subsequent calls are ignored.
+pub fn set_plain_output(plain: bool) {
+    let _ = PLAIN_OUTPUT.set(plain);
+}
+
+/// Whether output should be plain ASCII without emoji or colour.
+pub fn plain_output() -> bool {
+    *PLAIN_OUTPUT.get().unwrap_or(&false)
+}
+
+/// Sets how fetched resources are printed. Only the first call has an effect,
+/// subsequent calls are ignored.
+pub fn set_output_mode(output_mode: OutputMode) {
+    let _ = OUTPUT_MODE.set(output_mode);
+}
+
+/// How fetched resources should be printed.
+pub fn output_mode() -> OutputMode {
+    *OUTPUT_MODE.get().unwrap_or(&OutputMode::Text)
+}
+
+/// Prints a fetched resource to stdout, as JSON when the JSON output mode is
+/// enabled and otherwise with its debug representation.
+pub fn print_resource<T: Serialize + std::fmt::Debug>(resource: &T) {
+    match output_mode() {
+        OutputMode::Json => match serde_json::to_string_pretty(resource) {
+            Ok(json) => println!("{}", json),
+            Err(error) => eprintln!("Unable to serialize resource as JSON: {}", error),
+        },
+        OutputMode::Text => {
+            println!("{:#?}", resource);
+            println!();
+        }
+    }
+}
+
+/// Prints fetched resources to stdout as a table with a row per resource and a column
+/// for each of the resource's `Columns`. Printed as JSON instead when the JSON output
+/// mode is enabled. The header is coloured unless plain output is enabled.
+pub fn print_table<T: Serialize + std::fmt::Debug + Columns>(resources: &[T]) {
+    if output_mode() == OutputMode::Json {
+        print_resource(&resources);
+        return;
+    }
+
+    let mut table = Table::new();
+    if plain_output() {
+        table
+            .load_preset(presets::ASCII_FULL_CONDENSED)
+            .force_no_tty();
+    } else {
+        table.load_preset(presets::UTF8_FULL_CONDENSED);
+    }
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(T::HEADERS.iter().map(|column| {
+            Cell::new(column)
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan)
+        }));
+    for resource in resources {
+        table.add_row(resource.row());
+    }
+
+    println!("{table}");
+    println!();
+}
+
+/// Writes fetched resources to the file at `path` when given, as JSON or CSV based on
+/// its extension, and otherwise prints them as a table. Exits if the file can't be
+/// written.
+pub fn print_list<T: Serialize + std::fmt::Debug + Columns>(resources: &[T], path: Option<&Path>) {
+    let Some(path) = path else {
+        print_table(resources);
+        return;
+    };
+
+    if let Err(error) = export::export(path, resources) {
+        eprintln!("Unable to write {}: {}", path.display(), error);
+        process::exit(EXIT_ERROR);
+    }
+    eprintln!("Wrote {} resources to {}", resources.len(), path.display());
+}
+
+/// Returns `emoji` for display unless plain output is enabled, in which case
+/// the ASCII `plain` alternative is returned.
+pub fn symbol<'a>(emoji: &'a str, plain: &'a str) -> &'a str {
+    if plain_output() {
+        plain
+    } else {
+        emoji
+    }
+}
+
+/// Enables or disables dry run mode, in which destructive operations print the
+/// requests they would send rather than sending them. Only the first call has an
+/// effect, subsequent calls are ignored.
+pub fn set_dry_run(dry_run: bool) {
+    let _ = DRY_RUN.set(dry_run);
+}
+
+/// Whether destructive operations should only print the requests they would send.
+pub fn dry_run() -> bool {
+    *DRY_RUN.get().unwrap_or(&false)
+}
+
+/// Prints the `method` request that would be sent to each of `urls`, along with
+/// the form `body` if any, for a dry run.
+pub fn print_dry_run(method: &str, urls: &[String], body: Option<&str>) {
+    println!(
+        "Dry run: {} request(s) would be sent. No changes were made.",
+        urls.len()
+    );
+    for url in urls {
+        match body {
+            Some(body) => println!("  {} {} {}", method, url, body),
+            None => println!("  {} {}", method, url),
+        }
+    }
+    println!();
+}
+
+/// Prints how many items have been fetched so far, overwriting the previous count so
+/// progress is visible while paging through large lists. Printed to stderr so it doesn't
+/// mix with output piped from stdout. Print a new line to stderr once listing has finished.
+pub fn print_list_progress(progress: ListProgress) {
+    eprint!("\rFetched {} items...", progress.items);
+    let _ = io::stderr().flush();
+}
+
+/// Prints how many resources a bulk operation succeeded for along with any
+/// failures, e.g. `Deleted 9 of 10 Maps.`
+pub fn print_bulk_summary<K: Display, T>(
+    results: &[(K, Result<T, TwilioError>)],
+    action: &str,
+    resource: &str,
+) {
+    let failures = results
+        .iter()
+        .filter(|(_, result)| result.is_err())
+        .collect::<Vec<_>>();
+    println!(
+        "{} {} of {} {}.",
+        action,
+        results.len() - failures.len(),
+        results.len(),
+        resource
+    );
+    for (sid, result) in failures {
+        if let Err(error) = result {
+            println!("Failed for '{}': {}", sid, error);
+        }
+    }
+    println!();
+}
+
+/// Number of resources above which a bulk operation requires the user to type
+/// a confirmation rather than answer yes or no, unless set otherwise in the profile.
+pub const DEFAULT_BULK_CONFIRMATION_THRESHOLD: usize = 10;
+
+/// Loads credentials from the `TWILIO_*` environment variables, returning `None` when
+/// none of them are set. Exits if they are set but missing values or invalid.
+pub fn credentials_from_env() -> Option<TwilioConfig> {
+    let is_set = [ACCOUNT_SID_ENV, AUTH_TOKEN_ENV, API_KEY_ENV, API_SECRET_ENV]
+        .iter()
+        .any(|name| std::env::var_os(name).is_some());
+    if !is_set {
+        return None;
+    }
+
+    Some(TwilioConfig::from_env().unwrap_or_else(|error| exit_with_error(&error)))
+}
 
 /// Requests Twilio Account SID and auth token pair from the user and returns
-/// it as a `TwilioConfig` struct.
+/// it as a `TwilioConfig` struct. Prompts again until a valid pair is provided and
+/// exits if the user cancels either prompt.
 pub fn request_credentials() -> TwilioConfig {
-    let account_sid_prompt = Text::new("Please provide an account SID:")
-        .with_placeholder("AC...")
-        .with_validator(|val: &str| match val.starts_with("AC") {
-            true => Ok(Validation::Valid),
-            false => Ok(Validation::Invalid("Account SID must start with AC".into())),
-        })
-        .with_validator(|val: &str| match val.len() {
-            34 => Ok(Validation::Valid),
-            _ => Ok(Validation::Invalid(
-                "Your SID should be 34 characters in length".into(),
-            )),
-        });
-    let account_sid = prompt_user(account_sid_prompt).unwrap_or(String::from(""));
-
-    let auth_token_prompt = Password::new("Provide the auth token (input hidden):")
-        .with_validator(|val: &str| match val.len() {
-            32 => Ok(Validation::Valid),
-            _ => Ok(Validation::Invalid(
-                "Your SID should be 32 characters in length".into(),
-            )),
-        })
-        .with_display_mode(PasswordDisplayMode::Masked)
-        .with_display_toggle_enabled()
-        .without_confirmation()
-        .with_help_message("Input is masked. Use Ctrl + R to toggle visibility.");
-    let auth_token = prompt_user(auth_token_prompt).unwrap_or(String::from(""));
+    loop {
+        let account_sid_prompt = Text::new("Please provide an account SID:")
+            .with_placeholder("AC...")
+            .with_validator(sid_validator("AC"));
+        let account_sid = prompt_user(account_sid_prompt).unwrap_or_else(|| process::exit(0));
+
+        let auth_token_prompt = Password::new("Provide the auth token (input hidden):")
+            .with_validator(|val: &str| match val.len() {
+                32 => Ok(Validation::Valid),
+                _ => Ok(Validation::Invalid(
+                    "Your SID should be 32 characters in length".into(),
+                )),
+            })
+            .with_display_mode(PasswordDisplayMode::Masked)
+            .with_display_toggle_enabled()
+            .without_confirmation()
+            .with_help_message("Input is masked. Use Ctrl + R to toggle visibility.");
+        let auth_token = prompt_user(auth_token_prompt).unwrap_or_else(|| process::exit(0));
+
+        match TwilioConfig::build(account_sid, auth_token) {
+            Ok(config) => return config,
+            Err(error) => println!("{}. Please try again.", error),
+        }
+    }
+}
+
+/// Creates a validator for text prompts accepting only SIDs of the resource
+/// identified by `prefix`, e.g. `CH` for Conversations.
+pub fn sid_validator(prefix: &str) -> impl StringValidator {
+    let prefix = prefix.to_string();
+
+    move |val: &str| match validate_sid(&prefix, val) {
+        Ok(()) => Ok(Validation::Valid),
+        Err(TwilioError {
+            kind: ErrorKind::ValidationError(message),
+        }) => Ok(Validation::Invalid(message.into())),
+        Err(error) => Ok(Validation::Invalid(error.to_string().into())),
+    }
+}
+
+/// Creates a validator for text prompts accepting either a SID of the resource
+/// identified by `prefix` or a unique name. Only blank input is rejected as any other
+/// value could be a unique name.
+pub fn sid_or_unique_name_validator(prefix: &str) -> impl StringValidator {
+    let prefix = prefix.to_string();
 
-    TwilioConfig::build(account_sid, auth_token)
+    move |val: &str| match val.trim().is_empty() {
+        false => Ok(Validation::Valid),
+        true => Ok(Validation::Invalid(
+            format!("Provide a SID starting with {} or a unique name", prefix).into(),
+        )),
+    }
+}
+
+/// Determines the exit code describing why a request to Twilio failed.
+pub fn exit_code(error: &TwilioError) -> i32 {
+    match &error.kind {
+        ErrorKind::ValidationError(_) => EXIT_VALIDATION,
+        ErrorKind::CircuitOpen | ErrorKind::RateLimited { .. } => EXIT_RATE_LIMITED,
+        ErrorKind::Unauthorized(_) => EXIT_AUTHENTICATION,
+        ErrorKind::TwilioError(twilio_error) if twilio_error.throttle_reason().is_some() => {
+            EXIT_RATE_LIMITED
+        }
+        ErrorKind::TwilioError(twilio_error) => match twilio_error.status {
+            400 => EXIT_VALIDATION,
+            401 | 403 => EXIT_AUTHENTICATION,
+            500..=599 => EXIT_RATE_LIMITED,
+            _ => EXIT_ERROR,
+        },
+        ErrorKind::HttpError {
+            status: 401 | 403, ..
+        } => EXIT_AUTHENTICATION,
+        ErrorKind::HttpError {
+            status: 500..=599, ..
+        } => EXIT_RATE_LIMITED,
+        _ => EXIT_ERROR,
+    }
+}
+
+/// Reports an unrecoverable error from Twilio and exits with the matching
+/// exit code. See `exit_code`.
+pub fn exit_with_error(error: &TwilioError) -> ! {
+    eprintln!("{}", error);
+    if let Some(throttle_reason) = error.throttle_reason() {
+        eprintln!("Throttled by Twilio: {}", throttle_reason);
+    }
+    if let Some(request_id) = error.request_id() {
+        eprintln!("Twilio request ID: {}", request_id);
+    }
+    process::exit(exit_code(error));
 }
 
 /// A wrapper around the Inquire crates various input controls. This is used
@@ -99,7 +696,7 @@ fn handle_inquire_error<T>(error: InquireError) -> Option<T> {
         inquire::InquireError::OperationCanceled => None,
         inquire::InquireError::OperationInterrupted => {
             eprintln!("Operation interrupted. Closing program.");
-            process::exit(130);
+            process::exit(EXIT_INTERRUPTED);
         }
         inquire::InquireError::IO(err) => {
             panic!("Unhandled IO Error: {}", err);
@@ -138,21 +735,72 @@ pub fn prompt_user<T>(control: impl InquireControl<T>) -> Option<T> {
 /// from the user. If `None` is returned it is assumed the user
 /// un-forcefully cancelled the action, e.g. pressed ESC.
 ///
-/// This has the same pattern as `prompt_user` for obvious reasons.
-pub fn prompt_user_selection<T: Display>(control: Select<'_, T>) -> Option<T> {
+/// This has the same pattern as `prompt_user` for obvious reasons. The page size
+/// configured in the profile is applied along with navigation help unless the
+/// prompt provides its own help message.
+pub fn prompt_user_selection<T: Display>(mut control: Select<'_, T>) -> Option<T> {
+    control = control.with_page_size(page_size());
+    if control.help_message == Select::<T>::DEFAULT_HELP_MESSAGE {
+        control = control.with_help_message(symbol(SELECT_HELP_MESSAGE, PLAIN_SELECT_HELP_MESSAGE));
+    }
+
     match control.prompt() {
         Ok(result) => Some(result),
         Err(error) => handle_inquire_error(error),
     }
 }
 
-pub fn prompt_user_multi_selection<T: Display>(control: MultiSelect<'_, T>) -> Option<Vec<T>> {
+/// Prompts the user for one or more selections from the provided options, following
+/// the same pattern as `prompt_user_selection`.
+pub fn prompt_user_multi_selection<T: Display>(mut control: MultiSelect<'_, T>) -> Option<Vec<T>> {
+    control = control.with_page_size(page_size());
+    if control.help_message == MultiSelect::<T>::DEFAULT_HELP_MESSAGE {
+        control = control.with_help_message(symbol(
+            MULTI_SELECT_HELP_MESSAGE,
+            PLAIN_MULTI_SELECT_HELP_MESSAGE,
+        ));
+    }
+
     match control.prompt() {
         Ok(result) => Some(result),
         Err(error) => handle_inquire_error(error),
     }
 }
 
+/// Confirms a bulk operation with the user, scaling the confirmation required with the
+/// number of resources affected. Up to the profile's `bulk_confirmation_threshold` a
+/// single yes/no confirmation is requested, above it the user must type the exact count
+/// or the action itself in capitals, e.g. `CLOSE`.
+///
+/// `action` describes the operation, e.g. "delete", and is used in the prompt. Returns
+/// `true` only if the user confirmed.
+pub fn confirm_bulk_operation(count: usize, action: &str) -> bool {
+    if count <= bulk_confirmation_threshold() {
+        let message = format!("Are you sure you wish to {} {} resources?", action, count);
+        let confirmation_prompt = Confirm::new(&message)
+            .with_placeholder("N")
+            .with_default(false);
+
+        return prompt_user(confirmation_prompt).unwrap_or(false);
+    }
+
+    let confirmation_word = action.to_uppercase();
+    let message = format!(
+        "This will {} {} resources. Type {} or {} to continue:",
+        action, count, count, confirmation_word
+    );
+    let confirmation_prompt =
+        Text::new(&message).with_help_message("There is no going back. Press ESC to cancel.");
+
+    match prompt_user(confirmation_prompt) {
+        Some(confirmation) => {
+            let confirmation = confirmation.trim();
+            confirmation == count.to_string() || confirmation == confirmation_word
+        }
+        None => false,
+    }
+}
+
 /// The options available to filter search results.
 pub enum FilterChoice {
     /// Any option, not limited to anything.
@@ -1,28 +1,91 @@
 mod account;
+mod commands;
 mod conversation;
+mod doctor;
+mod messaging;
+mod phone_number;
+mod raw;
 mod serverless;
 mod sync;
 
-use std::{process, str::FromStr};
+use std::{io::IsTerminal, process, str::FromStr};
 
-use inquire::{Confirm, Select};
+use clap::Parser;
+use commands::Cli;
+use inquire::{ui::RenderConfig, Confirm, Select};
 use strum::IntoEnumIterator;
-use twilly::{self, SubResource, TwilioConfig};
-use twilly_cli::{prompt_user_selection, request_credentials};
+use twilly::{self, RetryConfig, SubResource, TwilioConfig};
+use twilly_cli::{
+    credentials_from_env, exit_with_error, load_profile, prompt_user_selection,
+    request_credentials, requests_per_second, set_bulk_confirmation_threshold, set_concurrency,
+    set_dry_run, set_output_mode, set_page_size, set_plain_output, set_requests_per_second,
+    set_sort_by_last_modified, store_profile, symbol, OutputMode, EXIT_AUTHENTICATION,
+    EXIT_VALIDATION,
+};
 
 #[tokio::main(flavor = "current_thread")]
 async fn main() {
-    print_welcome_message();
+    let mut cli = Cli::parse();
+
+    // Plain output is used when requested or when output is not going to a terminal,
+    // e.g. redirected to a file.
+    let plain = cli.plain
+        || std::env::var("TWILLY_PLAIN").is_ok_and(|value| value == "1")
+        || std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+        || !std::io::stdout().is_terminal();
+    set_plain_output(plain);
+    if plain {
+        inquire::set_global_render_config(RenderConfig::empty());
+    }
+    set_output_mode(cli.output);
+    set_dry_run(cli.dry_run);
 
     let mut loaded_config = false;
-    let mut config = confy::load::<TwilioConfig>("twilly", "profile").unwrap_or_else(|err| {
-        eprintln!("Unable to load profile configuration: {}", err);
-        TwilioConfig {
-            ..Default::default()
+    let mut profile = load_profile();
+    set_page_size(profile.page_size);
+    set_concurrency(profile.concurrency);
+    set_requests_per_second(profile.requests_per_second);
+    set_sort_by_last_modified(profile.sort_by_last_modified);
+    set_bulk_confirmation_threshold(profile.bulk_confirmation_threshold);
+    let mut config = profile.config.clone();
+    // Credentials from the environment take precedence over the profile, e.g. in CI.
+    let env_config = credentials_from_env();
+
+    // Commands run without prompting so rely on credentials from the environment or
+    // stored by a previous interactive session.
+    if let Some(command) = cli.command.take() {
+        let config = env_config.unwrap_or(config);
+        if !config.has_credentials() {
+            eprintln!("No credentials found. Set TWILIO_ACCOUNT_SID and TWILIO_AUTH_TOKEN or run twilly without a command to provide them");
+            process::exit(EXIT_AUTHENTICATION);
         }
-    });
 
-    if config.account_sid.is_empty() | config.auth_token.is_empty() {
+        let twilio = build_client(&config, &cli);
+        commands::run(&twilio, command, cli.export.as_deref()).await;
+        return;
+    }
+
+    // Every action is chosen through a prompt so JSON output still needs a terminal
+    // to answer them. Fail early rather than hang or exit mid-way through.
+    if cli.output == OutputMode::Json && !std::io::stdin().is_terminal() {
+        eprintln!("JSON output requires an interactive terminal to answer prompts. Pass a command to run without prompting");
+        process::exit(EXIT_VALIDATION);
+    }
+
+    // The banner would otherwise precede the JSON on stdout.
+    if cli.output == OutputMode::Text {
+        print_welcome_message();
+    }
+
+    if let Some(env_config) = env_config {
+        // Taken as is and not stored, the environment is the source of truth.
+        println!(
+            "Using account ({}) from environment variables.",
+            env_config.account_sid
+        );
+        config = env_config;
+        loaded_config = true;
+    } else if !config.has_credentials() {
         config = request_credentials();
     } else if Confirm::new(&format!(
         "Account ({}) found in memory. Use this profile?",
@@ -38,31 +101,48 @@ async fn main() {
         config = request_credentials();
     }
 
-    let twilio = twilly::Client::new(&config);
+    let twilio = build_client(&config, &cli);
 
     if !loaded_config {
         println!("Checking account...");
         let account = twilio
-            .accounts()
-            .get(None)
+            .verify_credentials()
             .await
-            .unwrap_or_else(|error| panic!("{}", error));
+            .unwrap_or_else(|error| exit_with_error(&error));
 
         println!(
-            "✅ Account details good! {} ({} - {})",
-            account.friendly_name, account.type_field, account.status
+            "{} Account details good! {} ({} - {})",
+            symbol("✅", "[OK]"),
+            account.friendly_name,
+            account.type_field,
+            account.status
         );
 
-        confy::store("twilly", "profile", &config)
-            .unwrap_or_else(|err| eprintln!("Unable to store profile configuration: {}", err));
+        // The last sync relates to the previous account's Conversations.
+        if profile.config.account_sid != config.account_sid {
+            profile.last_conversation_sync = None;
+        }
+        profile.config = config.clone();
+        if !store_profile(&profile) {
+            println!("Credentials will need to be provided again next launch.");
+            println!();
+        }
     }
 
+    // Shown above every main menu so it's clear which account actions will affect.
+    let mut account_summary = account::fetch_account_summary(&twilio).await;
+
     loop {
+        println!("{} {}", symbol("👤", "Account:"), account_summary);
         let mut sub_resource_options: Vec<String> = SubResource::iter()
             .map(|sub_resource| sub_resource.to_string())
             .collect();
-        let mut exit_option = vec![String::from("Exit")];
-        sub_resource_options.append(&mut exit_option);
+        let mut doctor_and_exit_options = vec![
+            String::from("Advanced: raw request"),
+            String::from("Doctor"),
+            String::from("Exit"),
+        ];
+        sub_resource_options.append(&mut doctor_and_exit_options);
         let sub_resource_choice_prompt = Select::new("Select a resource:", sub_resource_options);
         let sub_resource_choice = prompt_user_selection(sub_resource_choice_prompt);
 
@@ -77,13 +157,29 @@ async fn main() {
             process::exit(0);
         }
 
+        if sub_resource == "Advanced: raw request" {
+            raw::make_raw_request(&twilio).await;
+            continue;
+        }
+
+        if sub_resource == "Doctor" {
+            doctor::run_checks(&twilio).await;
+            continue;
+        }
+
         let sub_resource = SubResource::from_str(&sub_resource).unwrap();
 
         match sub_resource {
-            twilly::SubResource::Account => account::choose_account_action(&twilio).await,
+            twilly::SubResource::Account => {
+                account::choose_account_action(&twilio).await;
+                // Suspending, closing or activating may have changed the account's status.
+                account_summary = account::fetch_account_summary(&twilio).await;
+            }
             twilly::SubResource::Conversations => {
                 conversation::choose_conversation_action(&twilio).await
             }
+            twilly::SubResource::Messaging => messaging::choose_messaging_service(&twilio).await,
+            twilly::SubResource::PhoneNumbers => phone_number::choose_phone_number(&twilio).await,
             twilly::SubResource::Sync => sync::choose_sync_resource(&twilio).await,
             twilly::SubResource::Serverless => {
                 serverless::choose_serverless_resource(&twilio).await
@@ -108,3 +204,34 @@ fn print_welcome_message() {
     println!("Welcome to Twilly! I'm here to help you interact with Twilio!");
     println!();
 }
+
+/// Creates the client used for every request. Each request is printed as a cURL
+/// command to reproduce it when `curl` is set. The auth token is only included when
+/// `show_secrets` is also set. Requests are limited to the profile's
+/// `requests_per_second`.
+fn build_client(config: &TwilioConfig, cli: &Cli) -> twilly::Client {
+    let mut builder = twilly::Client::builder(config);
+    if let Some(region) = &cli.region {
+        builder = builder.region(region);
+    }
+    if let Some(edge) = &cli.edge {
+        builder = builder.edge(edge);
+    }
+    let twilio = builder
+        .build()
+        .unwrap_or_else(|error| exit_with_error(&error))
+        .with_rate_limit(requests_per_second())
+        .with_retry(RetryConfig {
+            failure_threshold: Some(5),
+            ..Default::default()
+        });
+
+    if cli.curl {
+        let show_secrets = cli.insecure_show_secrets;
+        twilio.with_request_observer(move |request| {
+            eprintln!("{}", request.to_curl(show_secrets));
+        })
+    } else {
+        twilio
+    }
+}
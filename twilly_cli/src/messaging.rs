@@ -0,0 +1,40 @@
+use std::process;
+
+use twilly::Client;
+use twilly_cli::{exit_with_error, get_action_choice_from_user, print_resource, ActionChoice};
+
+pub async fn choose_messaging_service(twilio: &Client) {
+    let messaging_services = twilio
+        .messaging()
+        .services()
+        .list()
+        .await
+        .unwrap_or_else(|error| exit_with_error(&error));
+
+    if messaging_services.is_empty() {
+        println!("No Messaging Services found.");
+        return;
+    }
+
+    println!("Found {} Messaging Services.", messaging_services.len());
+
+    while let Some(action_choice) = get_action_choice_from_user(
+        messaging_services
+            .iter()
+            .map(|service| format!("({}) {}", service.sid, service.friendly_name))
+            .collect::<Vec<String>>(),
+        "Choose a Messaging Service: ",
+    ) {
+        match action_choice {
+            ActionChoice::Back => break,
+            ActionChoice::Exit => process::exit(0),
+            ActionChoice::Other(choice) => {
+                let messaging_service = messaging_services
+                    .iter()
+                    .find(|service| choice.starts_with(&format!("({})", service.sid)))
+                    .expect("Could not find Messaging Service in existing Messaging Service list");
+                print_resource(messaging_service);
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::process;
+
+use inquire::Text;
+use twilly::Client;
+use twilly_cli::{
+    exit_with_error, get_action_choice_from_user, print_resource, prompt_user, ActionChoice,
+};
+
+pub async fn choose_phone_number(twilio: &Client) {
+    let Some(friendly_name) = prompt_user(Text::new("Search by friendly name? (empty for none):"))
+    else {
+        return;
+    };
+    let Some(phone_number) = prompt_user(
+        Text::new("Search by phone number? (empty for none):").with_placeholder("e.g. 415"),
+    ) else {
+        return;
+    };
+
+    let phone_numbers = twilio
+        .phone_numbers()
+        .incoming()
+        .list(
+            Some(friendly_name.trim()).filter(|friendly_name| !friendly_name.is_empty()),
+            Some(phone_number.trim()).filter(|phone_number| !phone_number.is_empty()),
+        )
+        .await
+        .unwrap_or_else(|error| exit_with_error(&error));
+
+    if phone_numbers.is_empty() {
+        println!("No phone numbers found.");
+        return;
+    }
+
+    println!("Found {} phone numbers.", phone_numbers.len());
+
+    while let Some(action_choice) = get_action_choice_from_user(
+        phone_numbers
+            .iter()
+            .map(|phone_number| {
+                format!(
+                    "{} ({})",
+                    phone_number.phone_number, phone_number.friendly_name
+                )
+            })
+            .collect::<Vec<String>>(),
+        "Choose a phone number: ",
+    ) {
+        match action_choice {
+            ActionChoice::Back => break,
+            ActionChoice::Exit => process::exit(0),
+            ActionChoice::Other(choice) => {
+                let phone_number = phone_numbers
+                    .iter()
+                    .find(|phone_number| {
+                        choice.starts_with(&format!("{} (", phone_number.phone_number))
+                    })
+                    .expect("Could not find phone number in existing phone number list");
+                print_resource(phone_number);
+            }
+        }
+    }
+}
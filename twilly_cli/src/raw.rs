@@ -0,0 +1,155 @@
+use inquire::{validator::Validation, Confirm, Select, Text};
+use serde_json::Value;
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter, EnumString};
+use twilly::{Client, Method};
+use twilly_cli::{prompt_user, prompt_user_selection};
+
+#[derive(Debug, Clone, Display, EnumIter, EnumString)]
+pub enum RequestMethod {
+    #[strum(to_string = "GET")]
+    Get,
+    #[strum(to_string = "POST")]
+    Post,
+    #[strum(to_string = "DELETE")]
+    Delete,
+}
+
+/// Twilio products the request path is relative to.
+#[derive(Debug, Clone, Display, EnumIter, EnumString)]
+pub enum Product {
+    #[strum(to_string = "API (/2010-04-01/Accounts/<account SID>)")]
+    Api,
+    Conversations,
+    Messaging,
+    Serverless,
+    Sync,
+}
+
+impl Product {
+    fn base_url(&self, account_sid: &str) -> String {
+        match self {
+            Product::Api => format!("https://api.twilio.com/2010-04-01/Accounts/{}", account_sid),
+            Product::Conversations => String::from("https://conversations.twilio.com/v1"),
+            Product::Messaging => String::from("https://messaging.twilio.com/v1"),
+            Product::Serverless => String::from("https://serverless.twilio.com/v1"),
+            Product::Sync => String::from("https://sync.twilio.com/v1"),
+        }
+    }
+}
+
+/// Sends a request built by the user to an endpoint the CLI doesn't otherwise
+/// cover, printing the JSON response or the error returned by Twilio.
+pub async fn make_raw_request(twilio: &Client) {
+    let method_prompt = Select::new("Select a method:", RequestMethod::iter().collect());
+    let Some(method) = prompt_user_selection(method_prompt) else {
+        return;
+    };
+
+    let product_prompt = Select::new("Select a product:", Product::iter().collect());
+    let Some(product) = prompt_user_selection(product_prompt) else {
+        return;
+    };
+
+    let base_url = product.base_url(&twilio.config.account_sid);
+    let path_message = format!("Enter the path relative to {}:", base_url);
+    let path_prompt = Text::new(&path_message)
+        .with_placeholder("/Services")
+        .with_validator(|val: &str| match val.trim().starts_with('/') {
+            true => Ok(Validation::Valid),
+            false => Ok(Validation::Invalid("Path must start with '/'".into())),
+        });
+    let Some(path) = prompt_user(path_prompt) else {
+        return;
+    };
+
+    let params_prompt = Text::new("Enter any parameters as a JSON object:")
+        .with_placeholder("{\"FriendlyName\": \"Example\"}")
+        .with_help_message("Leave empty to send no parameters. Lists repeat the parameter.")
+        .with_validator(|val: &str| {
+            if val.trim().is_empty() {
+                return Ok(Validation::Valid);
+            }
+
+            match serde_json::from_str::<Value>(val).map_err(|error| error.to_string()) {
+                Ok(value) => match json_to_params(&value) {
+                    Ok(_) => Ok(Validation::Valid),
+                    Err(error) => Ok(Validation::Invalid(error.into())),
+                },
+                Err(error) => Ok(Validation::Invalid(error.into())),
+            }
+        });
+    let Some(params) = prompt_user(params_prompt) else {
+        return;
+    };
+    let params = match params.trim().is_empty() {
+        true => None,
+        false => Some(
+            serde_json::from_str::<Value>(&params)
+                .map_err(|error| error.to_string())
+                .and_then(|value| json_to_params(&value))
+                .expect("Unable to parse validated parameters"),
+        ),
+    };
+
+    let url = format!("{}{}", base_url, path.trim());
+    let method = match method {
+        RequestMethod::Get => Method::GET,
+        RequestMethod::Post => Method::POST,
+        RequestMethod::Delete => Method::DELETE,
+    };
+
+    if method != Method::GET {
+        let confirm_message = format!(
+            "Are you sure you wish to send a {} request to {}?",
+            method, url
+        );
+        let confirm_prompt = Confirm::new(&confirm_message)
+            .with_placeholder("N")
+            .with_default(false);
+
+        if prompt_user(confirm_prompt) != Some(true) {
+            println!("Operation canceled. No request was sent.");
+            println!();
+            return;
+        }
+    }
+
+    match twilio.raw_request(method, &url, params.as_deref()).await {
+        Ok(Value::Null) => println!("Request succeeded."),
+        Ok(response) => println!(
+            "{}",
+            serde_json::to_string_pretty(&response).expect("Unable to format response")
+        ),
+        Err(error) => println!("Request failed: {}", error),
+    }
+    println!();
+}
+
+// Flattens a JSON object into form parameters. Lists become repeated parameters,
+// nested objects aren't supported by Twilio so are rejected.
+fn json_to_params(value: &Value) -> Result<Vec<(String, String)>, String> {
+    let Value::Object(object) = value else {
+        return Err(String::from("Parameters must be a JSON object"));
+    };
+
+    let mut params = Vec::new();
+    for (key, value) in object {
+        let values = match value {
+            Value::Array(values) => values.iter().collect(),
+            value => vec![value],
+        };
+
+        for value in values {
+            match value {
+                Value::String(value) => params.push((key.clone(), value.clone())),
+                Value::Object(_) | Value::Array(_) => {
+                    return Err(format!("'{}' must be a string, number or boolean", key))
+                }
+                value => params.push((key.clone(), value.to_string())),
+            }
+        }
+    }
+
+    Ok(params)
+}
@@ -7,7 +7,10 @@ use regex::Regex;
 use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter, EnumString};
 use twilly::{serverless::services::CreateOrUpdateParams, Client};
-use twilly_cli::{get_action_choice_from_user, prompt_user, prompt_user_selection, ActionChoice};
+use twilly_cli::{
+    exit_with_error, get_action_choice_from_user, last_modified_suffix, print_resource,
+    prompt_user, prompt_user_selection, sort_by_last_modified, ActionChoice, LOAD_MORE_OPTION,
+};
 
 #[derive(Debug, Clone, Display, EnumIter, EnumString)]
 pub enum Action {
@@ -20,19 +23,29 @@ pub enum Action {
 }
 
 pub async fn choose_serverless_resource(twilio: &Client) {
-    let mut serverless_services = twilio
+    // Services are loaded a page at a time so the menu appears quickly on large accounts.
+    let serverless_services_page = twilio
         .serverless()
         .services()
-        .list()
+        .page(None)
         .await
-        .unwrap_or_else(|error| panic!("{}", error));
+        .unwrap_or_else(|error| exit_with_error(&error));
+    let mut serverless_services = serverless_services_page.items;
+    sort_by_last_modified(&mut serverless_services, |service| &service.date_updated);
+    let mut next_page_url = serverless_services_page.next_page_url;
 
     if serverless_services.is_empty() {
         println!("No Serverless Services found.");
         return;
     }
 
-    println!("Found {} Serverless Services.", serverless_services.len());
+    match next_page_url {
+        Some(_) => println!(
+            "Loaded the first {} Serverless Services.",
+            serverless_services.len()
+        ),
+        None => println!("Found {} Serverless Services.", serverless_services.len()),
+    }
 
     let mut selected_serverless_service_index: Option<usize> = None;
     loop {
@@ -41,9 +54,19 @@ pub async fn choose_serverless_resource(twilio: &Client) {
         } else {
             let mut existing_services = serverless_services
                 .iter()
-                .map(|service| format!("({}) {}", service.sid, service.unique_name))
+                .map(|service| {
+                    format!(
+                        "({}) {}{}",
+                        service.sid,
+                        service.unique_name,
+                        last_modified_suffix(&service.date_updated)
+                    )
+                })
                 .collect::<Vec<String>>();
             existing_services.push("Create Serverless Service".into());
+            if next_page_url.is_some() {
+                existing_services.push(LOAD_MORE_OPTION.into());
+            }
             if let Some(action_choice) =
                 get_action_choice_from_user(existing_services, "Choose a Serverless Service: ")
             {
@@ -117,7 +140,7 @@ pub async fn choose_serverless_resource(twilio: &Client) {
                                                     ui_editable: Some(ui_editable_confirmation),
                                                 })
                                                 .await
-                                                .unwrap_or_else(|error| panic!("{}", error));
+                                                .unwrap_or_else(|error| exit_with_error(&error));
                                             serverless_services.push(serverless_service);
                                             selected_serverless_service_index =
                                                 Some(serverless_services.len() - 1);
@@ -135,6 +158,20 @@ pub async fn choose_serverless_resource(twilio: &Client) {
                             } else {
                                 break;
                             }
+                        } else if choice == LOAD_MORE_OPTION {
+                            let serverless_services_page = twilio
+                                .serverless()
+                                .services()
+                                .page(next_page_url.as_deref())
+                                .await
+                                .unwrap_or_else(|error| exit_with_error(&error));
+                            serverless_services.extend(serverless_services_page.items);
+                            sort_by_last_modified(&mut serverless_services, |service| {
+                                &service.date_updated
+                            });
+                            next_page_url = serverless_services_page.next_page_url;
+                            println!("Loaded {} Serverless Services.", serverless_services.len());
+                            continue;
                         } else {
                             let serverless_service_position = serverless_services
                                 .iter()
@@ -160,8 +197,7 @@ pub async fn choose_serverless_resource(twilio: &Client) {
         if let Some(resource) = prompt_user_selection(resource_selection_prompt) {
             match resource {
                 Action::ListDetails => {
-                    println!("{:#?}", selected_serverless_service);
-                    println!();
+                    print_resource(&selected_serverless_service);
                 }
                 Action::Environments => {
                     environments::choose_environment_action(twilio, selected_serverless_service)
@@ -180,7 +216,7 @@ pub async fn choose_serverless_resource(twilio: &Client) {
                             .service(&selected_serverless_service.sid)
                             .delete()
                             .await
-                            .unwrap_or_else(|error| panic!("{}", error));
+                            .unwrap_or_else(|error| exit_with_error(&error));
                         serverless_services.remove(
                             selected_serverless_service_index.expect(
                                 "Could not find Serverless Service in existing Serverless Services list",
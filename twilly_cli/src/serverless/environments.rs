@@ -6,7 +6,10 @@ use inquire::{Confirm, Select};
 use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter, EnumString};
 use twilly::{serverless::services::ServerlessService, Client};
-use twilly_cli::{get_action_choice_from_user, prompt_user, prompt_user_selection, ActionChoice};
+use twilly_cli::{
+    exit_with_error, get_action_choice_from_user, last_modified_suffix, print_resource,
+    prompt_user, prompt_user_selection, sort_by_last_modified, ActionChoice, LOAD_MORE_OPTION,
+};
 
 #[derive(Debug, Clone, Display, EnumIter, EnumString)]
 pub enum Action {
@@ -19,23 +22,34 @@ pub enum Action {
 }
 
 pub async fn choose_environment_action(twilio: &Client, serverless_service: &ServerlessService) {
-    let mut serverless_environments = twilio
+    let serverless_environments_page = twilio
         .serverless()
         .service(&serverless_service.sid)
         .environments()
-        .list()
+        .page(None)
         .await
-        .unwrap_or_else(|error| panic!("{}", error));
+        .unwrap_or_else(|error| exit_with_error(&error));
+    let mut serverless_environments = serverless_environments_page.items;
+    sort_by_last_modified(&mut serverless_environments, |environment| {
+        &environment.date_updated
+    });
+    let mut next_page_url = serverless_environments_page.next_page_url;
 
     if serverless_environments.is_empty() {
         println!("No Serverless Environments found.");
         return;
     }
 
-    println!(
-        "Found {} Serverless Environments.",
-        serverless_environments.len()
-    );
+    match next_page_url {
+        Some(_) => println!(
+            "Loaded the first {} Serverless Environments.",
+            serverless_environments.len()
+        ),
+        None => println!(
+            "Found {} Serverless Environments.",
+            serverless_environments.len()
+        ),
+    }
 
     let mut selected_serverless_environment_index: Option<usize> = None;
     loop {
@@ -46,7 +60,15 @@ pub async fn choose_environment_action(twilio: &Client, serverless_service: &Ser
         } else if let Some(action_choice) = get_action_choice_from_user(
             serverless_environments
                 .iter()
-                .map(|environment| format!("({}) {}", environment.sid, environment.unique_name))
+                .map(|environment| {
+                    format!(
+                        "({}) {}{}",
+                        environment.sid,
+                        environment.unique_name,
+                        last_modified_suffix(&environment.date_updated)
+                    )
+                })
+                .chain(next_page_url.as_ref().map(|_| LOAD_MORE_OPTION.to_string()))
                 .collect::<Vec<String>>(),
             "Choose a Serverless Environment: ",
         ) {
@@ -55,6 +77,25 @@ pub async fn choose_environment_action(twilio: &Client, serverless_service: &Ser
                     break;
                 }
                 ActionChoice::Exit => process::exit(0),
+                ActionChoice::Other(choice) if choice == LOAD_MORE_OPTION => {
+                    let serverless_environments_page = twilio
+                        .serverless()
+                        .service(&serverless_service.sid)
+                        .environments()
+                        .page(next_page_url.as_deref())
+                        .await
+                        .unwrap_or_else(|error| exit_with_error(&error));
+                    serverless_environments.extend(serverless_environments_page.items);
+                    sort_by_last_modified(&mut serverless_environments, |environment| {
+                        &environment.date_updated
+                    });
+                    next_page_url = serverless_environments_page.next_page_url;
+                    println!(
+                        "Loaded {} Serverless Environments.",
+                        serverless_environments.len()
+                    );
+                    continue;
+                }
                 ActionChoice::Other(choice) => {
                     let serverless_environment_position = serverless_environments
                         .iter()
@@ -74,8 +115,7 @@ pub async fn choose_environment_action(twilio: &Client, serverless_service: &Ser
         if let Some(resource) = prompt_user_selection(resource_selection_prompt) {
             match resource {
                 Action::ListDetails => {
-                    println!("{:#?}", selected_serverless_environment);
-                    println!();
+                    print_resource(&selected_serverless_environment);
                 }
                 Action::Logs => {
                     logs::choose_log_action(
@@ -99,7 +139,7 @@ pub async fn choose_environment_action(twilio: &Client, serverless_service: &Ser
                             .list(&selected_serverless_environment.sid)
                             .delete()
                             .await
-                            .unwrap_or_else(|error| panic!("{}", error));
+                            .unwrap_or_else(|error| exit_with_error(&error));
                         serverless_environments.remove(
                             selected_serverless_environment_index
                                 .expect("Could not find Serverless Environment in existing Serverless Environment list"),
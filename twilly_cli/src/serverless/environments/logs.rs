@@ -1,19 +1,24 @@
 use chrono::{Datelike, Duration};
-use std::{fs::File, io::Write, process};
+use std::{path::Path, process};
 
-use inquire::{validator::Validation, Confirm, MultiSelect, Select, Text};
+use inquire::{Confirm, MultiSelect, Select, Text};
 use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter, EnumString};
 use twilly::{
     serverless::{
-        environments::{logs::Level, ServerlessEnvironment},
+        environments::{
+            logs::{Level, ServerlessLog},
+            ServerlessEnvironment,
+        },
         services::ServerlessService,
     },
-    Client, ErrorKind,
+    Client, ErrorKind, TwilioError,
 };
 use twilly_cli::{
-    get_action_choice_from_user, get_date_from_user, prompt_user, prompt_user_multi_selection,
-    prompt_user_selection, ActionChoice, DateRange,
+    exit_with_error,
+    export::{export_as, ExportFormat},
+    get_action_choice_from_user, get_date_from_user, print_resource, prompt_user,
+    prompt_user_multi_selection, prompt_user_selection, sid_validator, ActionChoice, DateRange,
 };
 
 /// Actions general to Logs.
@@ -63,15 +68,7 @@ pub async fn choose_log_action(
                 LogsAction::GetLog => {
                     let log_sid_prompt = Text::new("Please provide a Log SID:")
                         .with_placeholder("NO...")
-                        .with_validator(|val: &str| {
-                            if val.starts_with("NO") && val.len() == 34 {
-                                Ok(Validation::Valid)
-                            } else {
-                                Ok(Validation::Invalid(
-                                    "Log SID should be 34 characters in length".into(),
-                                ))
-                            }
-                        });
+                        .with_validator(sid_validator("NO"));
 
                     if let Some(log_sid) = prompt_user(log_sid_prompt) {
                         match twilio
@@ -97,8 +94,7 @@ pub async fn choose_log_action(
                                         ActionChoice::Exit => process::exit(0),
                                         ActionChoice::Other(choice) => match choice.as_str() {
                                             "List Details" => {
-                                                println!("{:#?}", log);
-                                                println!();
+                                                print_resource(&log);
                                             }
                                             _ => println!("Unknown action '{}'", choice),
                                         },
@@ -113,10 +109,12 @@ pub async fn choose_log_action(
                                         println!("A Log with SID '{}' was not found.", &log_sid);
                                         println!();
                                     } else {
-                                        panic!("{}", twilio_error);
+                                        exit_with_error(&TwilioError {
+                                            kind: ErrorKind::TwilioError(twilio_error),
+                                        });
                                     }
                                 }
-                                _ => panic!("{}", error),
+                                _ => exit_with_error(&error),
                             },
                         }
                     }
@@ -269,15 +267,7 @@ pub async fn choose_log_action(
                                 let function_sid_prompt =
                                     Text::new("Please provide a function SID:")
                                         .with_placeholder("ZH...")
-                                        .with_validator(|val: &str| {
-                                            if val.starts_with("ZH") && val.len() == 34 {
-                                                Ok(Validation::Valid)
-                                            } else {
-                                                Ok(Validation::Invalid(
-                                        "Function SID should be 34 characters in length".into(),
-                                    ))
-                                            }
-                                        });
+                                        .with_validator(sid_validator("ZH"));
 
                                 if let Some(user_function_sid) = prompt_user(function_sid_prompt) {
                                     function_sid = Some(user_function_sid);
@@ -301,10 +291,14 @@ pub async fn choose_log_action(
                                     .logs()
                                     .list(function_sid, start_date, end_date)
                                     .await
-                                    .unwrap_or_else(|error| panic!("{}", error));
+                                    .unwrap_or_else(|error| exit_with_error(&error));
 
                                 println!("Filtering...");
-                                serverless_logs.retain(|log| log_levels.contains(&log.level));
+                                // Levels unknown to twilly can't be selected so are always kept.
+                                serverless_logs.retain(|log| {
+                                    matches!(log.level, Level::Other(_))
+                                        || log_levels.contains(&log.level)
+                                });
 
                                 let number_of_logs = serverless_logs.len();
 
@@ -325,32 +319,10 @@ pub async fn choose_log_action(
                                             }
                                             ActionChoice::Exit => process::exit(0),
                                             ActionChoice::Other(choice) => match choice.as_str() {
-                                                "Write to file" => {
-                                                    match File::create(format!(
-                                                        "{}.json",
-                                                        &serverless_environment.sid
-                                                    )) {
-                                                        Ok(mut file_buffer) => {
-                                                            match file_buffer
-                                                                .write_all(
-                                                                    serde_json::to_string_pretty(
-                                                                        &serverless_logs,
-                                                                    )
-                                                                    .unwrap()
-                                                                    .as_bytes(),
-                                                                ) {
-																	Ok(_) => {
-																		println!("Log file created: {}.json", &serverless_environment.sid); 
-																		println!();
-																	},
-																	Err(error) => eprintln!("Failed to fully write to log file. Action aborted: {}", error)
-																}
-                                                        }
-                                                        Err(error) => eprintln!(
-                                                            "Unable to create log file. Action aborted: {}", error
-                                                        ),
-                                                    }
-                                                }
+                                                "Write to file" => write_logs_to_file(
+                                                    &serverless_environment.sid,
+                                                    &serverless_logs,
+                                                ),
                                                 "View" => {
                                                     // Sort date descending (latest first)
                                                     serverless_logs.sort_by(|a, b| {
@@ -373,9 +345,10 @@ pub async fn choose_log_action(
                                                                     .iter()
                                                                     .map(|log| {
                                                                         format!(
-                                                                            "({}) {} - {}",
+                                                                            "({}) {} [{}] - {}",
                                                                             log.sid,
                                                                             log.date_created,
+                                                                            log.level,
                                                                             log.message
                                                                         )
                                                                     })
@@ -419,11 +392,9 @@ pub async fn choose_log_action(
                                                         ) {
                                                             match action {
                                                                 LogAction::ListDetails => {
-                                                                    println!(
-                                                                        "{:#?}",
-                                                                        selected_serverless_log
+                                                                    print_resource(
+                                                                        &selected_serverless_log,
                                                                     );
-                                                                    println!();
                                                                 }
                                                                 LogAction::Back => {
                                                                     break;
@@ -450,3 +421,31 @@ pub async fn choose_log_action(
         }
     }
 }
+
+/// Writes `logs` to a file named by the user in the format of their choice. The file name
+/// defaults to the SID of the Environment.
+fn write_logs_to_file(environment_sid: &str, logs: &[ServerlessLog]) {
+    let format_prompt = Select::new(
+        "Select a format:",
+        ExportFormat::iter().collect::<Vec<ExportFormat>>(),
+    );
+    let Some(format) = prompt_user_selection(format_prompt) else {
+        println!("Operation canceled. No file was written.");
+        return;
+    };
+
+    let default_file_name = format!("{}.{}", environment_sid, format.extension());
+    let file_name_prompt = Text::new("File name:").with_default(&default_file_name);
+    let Some(file_name) = prompt_user(file_name_prompt) else {
+        println!("Operation canceled. No file was written.");
+        return;
+    };
+
+    match export_as(Path::new(&file_name), format, logs) {
+        Ok(_) => {
+            println!("Log file created: {}", file_name);
+            println!();
+        }
+        Err(error) => eprintln!("Unable to write log file. Action aborted: {}", error),
+    }
+}
@@ -10,7 +10,11 @@ use inquire::{Confirm, Select, Text};
 use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter, EnumString};
 use twilly::{sync::services::CreateOrUpdateParams, Client};
-use twilly_cli::{get_action_choice_from_user, prompt_user, prompt_user_selection, ActionChoice};
+use twilly_cli::{
+    concurrency, dry_run, exit_with_error, get_action_choice_from_user, last_modified_suffix,
+    print_bulk_summary, print_dry_run, print_resource, prompt_user, prompt_user_selection,
+    sort_by_last_modified, ActionChoice,
+};
 
 #[derive(Debug, Clone, Display, EnumIter, EnumString)]
 pub enum Action {
@@ -22,6 +26,12 @@ pub enum Action {
     List,
     #[strum(to_string = "List Details")]
     ListDetails,
+    #[strum(to_string = "Delete all Documents")]
+    DeleteAllDocuments,
+    #[strum(to_string = "Delete all Maps")]
+    DeleteAllMaps,
+    #[strum(to_string = "Delete all Lists")]
+    DeleteAllLists,
     Delete,
     Back,
     Exit,
@@ -33,7 +43,7 @@ pub async fn choose_sync_resource(twilio: &Client) {
         .services()
         .list()
         .await
-        .unwrap_or_else(|error| panic!("{}", error));
+        .unwrap_or_else(|error| exit_with_error(&error));
 
     if sync_services.is_empty() {
         println!("No Sync Services found.");
@@ -41,6 +51,7 @@ pub async fn choose_sync_resource(twilio: &Client) {
     }
 
     println!("Found {} Sync Services.", sync_services.len());
+    sort_by_last_modified(&mut sync_services, |service| &service.date_updated);
 
     let mut selected_sync_service_index: Option<usize> = None;
     loop {
@@ -50,9 +61,19 @@ pub async fn choose_sync_resource(twilio: &Client) {
             let mut existing_services = sync_services
                 .iter()
                 .map(|service| match &service.unique_name {
-                    Some(unique_name) => format!("({}) {}", service.sid, unique_name),
+                    Some(unique_name) => format!(
+                        "({}) {}{}",
+                        service.sid,
+                        unique_name,
+                        last_modified_suffix(&service.date_updated)
+                    ),
                     None => match &service.friendly_name {
-                        Some(friendly_name) => format!("({}) {}", service.sid, friendly_name),
+                        Some(friendly_name) => format!(
+                            "({}) {}{}",
+                            service.sid,
+                            friendly_name,
+                            last_modified_suffix(&service.date_updated)
+                        ),
                         None => service.sid.to_string(),
                     },
                 })
@@ -92,7 +113,7 @@ pub async fn choose_sync_resource(twilio: &Client) {
                                             webhook_url: None,
                                         })
                                         .await
-                                        .unwrap_or_else(|error| panic!("{}", error));
+                                        .unwrap_or_else(|error| exit_with_error(&error));
                                     sync_services.push(sync_service);
                                     selected_sync_service_index = Some(sync_services.len() - 1);
                                     &mut sync_services[selected_sync_service_index.unwrap()]
@@ -130,8 +151,79 @@ pub async fn choose_sync_resource(twilio: &Client) {
                 Action::Map => maps::choose_map_action(twilio, selected_sync_service).await,
                 Action::List => lists::choose_list_action(twilio, selected_sync_service).await,
                 Action::ListDetails => {
-                    println!("{:#?}", selected_sync_service);
-                    println!();
+                    print_resource(&selected_sync_service);
+                }
+                Action::DeleteAllDocuments => {
+                    if dry_run() {
+                        let urls = twilio
+                            .sync()
+                            .service(&selected_sync_service.sid)
+                            .documents()
+                            .list()
+                            .await
+                            .unwrap_or_else(|error| exit_with_error(&error))
+                            .into_iter()
+                            .map(|resource| resource.url)
+                            .collect::<Vec<String>>();
+                        print_dry_run("DELETE", &urls, None);
+                    } else if confirm_delete_all("Documents") {
+                        println!("Deleting Documents...");
+                        let results = twilio
+                            .sync()
+                            .service(&selected_sync_service.sid)
+                            .delete_all_documents(concurrency())
+                            .await
+                            .unwrap_or_else(|error| exit_with_error(&error));
+                        print_bulk_summary(&results, "Deleted", "Documents");
+                    }
+                }
+                Action::DeleteAllMaps => {
+                    if dry_run() {
+                        let urls = twilio
+                            .sync()
+                            .service(&selected_sync_service.sid)
+                            .maps()
+                            .list()
+                            .await
+                            .unwrap_or_else(|error| exit_with_error(&error))
+                            .into_iter()
+                            .map(|resource| resource.url)
+                            .collect::<Vec<String>>();
+                        print_dry_run("DELETE", &urls, None);
+                    } else if confirm_delete_all("Maps") {
+                        println!("Deleting Maps...");
+                        let results = twilio
+                            .sync()
+                            .service(&selected_sync_service.sid)
+                            .delete_all_maps(concurrency())
+                            .await
+                            .unwrap_or_else(|error| exit_with_error(&error));
+                        print_bulk_summary(&results, "Deleted", "Maps");
+                    }
+                }
+                Action::DeleteAllLists => {
+                    if dry_run() {
+                        let urls = twilio
+                            .sync()
+                            .service(&selected_sync_service.sid)
+                            .lists()
+                            .list()
+                            .await
+                            .unwrap_or_else(|error| exit_with_error(&error))
+                            .into_iter()
+                            .map(|resource| resource.url)
+                            .collect::<Vec<String>>();
+                        print_dry_run("DELETE", &urls, None);
+                    } else if confirm_delete_all("Lists") {
+                        println!("Deleting Lists...");
+                        let results = twilio
+                            .sync()
+                            .service(&selected_sync_service.sid)
+                            .delete_all_lists(concurrency())
+                            .await
+                            .unwrap_or_else(|error| exit_with_error(&error));
+                        print_bulk_summary(&results, "Deleted", "Lists");
+                    }
                 }
                 Action::Delete => {
                     let confirm_prompt =
@@ -146,7 +238,7 @@ pub async fn choose_sync_resource(twilio: &Client) {
                             .service(&selected_sync_service.sid)
                             .delete()
                             .await
-                            .unwrap_or_else(|error| panic!("{}", error));
+                            .unwrap_or_else(|error| exit_with_error(&error));
                         sync_services.remove(
                             selected_sync_service_index.expect(
                                 "Could not find Sync Service in existing Sync Services list",
@@ -165,3 +257,17 @@ pub async fn choose_sync_resource(twilio: &Client) {
         }
     }
 }
+
+/// Asks the user to confirm deleting every resource of the given type
+/// within the Sync Service.
+fn confirm_delete_all(resource: &str) -> bool {
+    let message = format!(
+        "Are you sure you wish to delete **all** {} in the Sync Service?",
+        resource
+    );
+    let confirm_prompt = Confirm::new(&message)
+        .with_placeholder("N")
+        .with_default(false);
+
+    matches!(prompt_user(confirm_prompt), Some(true))
+}
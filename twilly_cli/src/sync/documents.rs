@@ -1,10 +1,20 @@
 use std::process;
 
 use inquire::{validator::Validation, Confirm, Select, Text};
+use serde_json::Value;
 use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter, EnumString};
-use twilly::{sync::services::SyncService, Client, ErrorKind};
-use twilly_cli::{get_action_choice_from_user, prompt_user, prompt_user_selection, ActionChoice};
+use twilly::{
+    sync::{
+        documents::{SyncDocument, UpdateParams},
+        services::SyncService,
+    },
+    Client, ErrorKind, TwilioError,
+};
+use twilly_cli::{
+    exit_with_error, get_action_choice_from_user, last_modified_suffix, print_resource,
+    prompt_user, prompt_user_selection, sid_validator, sort_by_last_modified, ActionChoice,
+};
 
 #[derive(Debug, Clone, Display, EnumIter, EnumString)]
 pub enum Action {
@@ -28,18 +38,7 @@ pub async fn choose_document_action(twilio: &Client, sync_service: &SyncService)
                     let document_sid_prompt =
                         Text::new("Please provide a document SID (or unique name):")
                             .with_placeholder("ET...")
-                            .with_validator(|val: &str| match val.starts_with("ET") {
-                                true => Ok(Validation::Valid),
-                                false => Ok(Validation::Invalid(
-                                    "Document SID must start with ET".into(),
-                                )),
-                            })
-                            .with_validator(|val: &str| match val.len() {
-                                34 => Ok(Validation::Valid),
-                                _ => Ok(Validation::Invalid(
-                                    "Your SID should be 34 characters in length".into(),
-                                )),
-                            });
+                            .with_validator(sid_validator("ET"));
 
                     if let Some(document_sid) = prompt_user(document_sid_prompt) {
                         match twilio
@@ -49,9 +48,13 @@ pub async fn choose_document_action(twilio: &Client, sync_service: &SyncService)
                             .get()
                             .await
                         {
-                            Ok(document) => loop {
+                            Ok(mut document) => loop {
                                 if let Some(action_choice) = get_action_choice_from_user(
-                                    vec![String::from("List Details"), String::from("Delete")],
+                                    vec![
+                                        String::from("List Details"),
+                                        String::from("Update data"),
+                                        String::from("Delete"),
+                                    ],
                                     "Select an action: ",
                                 ) {
                                     match action_choice {
@@ -61,8 +64,19 @@ pub async fn choose_document_action(twilio: &Client, sync_service: &SyncService)
                                         ActionChoice::Exit => process::exit(0),
                                         ActionChoice::Other(choice) => match choice.as_str() {
                                             "List Details" => {
-                                                println!("{:#?}", document);
-                                                println!();
+                                                print_resource(&document);
+                                            }
+                                            "Update data" => {
+                                                if let Some(updated_document) =
+                                                    update_document_data(
+                                                        twilio,
+                                                        sync_service,
+                                                        &document,
+                                                    )
+                                                    .await
+                                                {
+                                                    document = updated_document;
+                                                }
                                             }
                                             "Delete" => {
                                                 let confirm_prompt = Confirm::new(
@@ -80,7 +94,7 @@ pub async fn choose_document_action(twilio: &Client, sync_service: &SyncService)
                                                         .delete()
                                                         .await
                                                         .unwrap_or_else(|error| {
-                                                            panic!("{}", error)
+                                                            exit_with_error(&error)
                                                         });
                                                     println!("Document deleted.");
                                                     println!();
@@ -101,10 +115,12 @@ pub async fn choose_document_action(twilio: &Client, sync_service: &SyncService)
                                         );
                                         println!();
                                     } else {
-                                        panic!("{}", twilio_error);
+                                        exit_with_error(&TwilioError {
+                                            kind: ErrorKind::TwilioError(twilio_error),
+                                        });
                                     }
                                 }
-                                _ => panic!("{}", error),
+                                _ => exit_with_error(&error),
                             },
                         }
                     }
@@ -117,7 +133,7 @@ pub async fn choose_document_action(twilio: &Client, sync_service: &SyncService)
                         .documents()
                         .list()
                         .await
-                        .unwrap_or_else(|error| panic!("{}", error));
+                        .unwrap_or_else(|error| exit_with_error(&error));
 
                     let number_of_documents = documents.len();
 
@@ -126,6 +142,7 @@ pub async fn choose_document_action(twilio: &Client, sync_service: &SyncService)
                         println!();
                     } else {
                         println!("Found {} Documents.", number_of_documents);
+                        sort_by_last_modified(&mut documents, |doc| &doc.date_updated);
 
                         let mut selected_document_index: Option<usize> = None;
                         loop {
@@ -134,7 +151,14 @@ pub async fn choose_document_action(twilio: &Client, sync_service: &SyncService)
                             } else if let Some(action_choice) = get_action_choice_from_user(
                                 documents
                                     .iter()
-                                    .map(|doc| format!("({}) {}", doc.sid, doc.unique_name))
+                                    .map(|doc| {
+                                        format!(
+                                            "({}) {}{}",
+                                            doc.sid,
+                                            doc.unique_name,
+                                            last_modified_suffix(&doc.date_updated)
+                                        )
+                                    })
                                     .collect::<Vec<String>>(),
                                 "Documents: ",
                             ) {
@@ -160,7 +184,11 @@ pub async fn choose_document_action(twilio: &Client, sync_service: &SyncService)
 
                             loop {
                                 if let Some(action_choice) = get_action_choice_from_user(
-                                    vec![String::from("List Details"), String::from("Delete")],
+                                    vec![
+                                        String::from("List Details"),
+                                        String::from("Update data"),
+                                        String::from("Delete"),
+                                    ],
                                     "Select an action: ",
                                 ) {
                                     match action_choice {
@@ -171,8 +199,19 @@ pub async fn choose_document_action(twilio: &Client, sync_service: &SyncService)
                                         ActionChoice::Exit => process::exit(0),
                                         ActionChoice::Other(choice) => match choice.as_str() {
                                             "List Details" => {
-                                                println!("{:#?}", selected_document);
-                                                println!();
+                                                print_resource(&selected_document);
+                                            }
+                                            "Update data" => {
+                                                if let Some(updated_document) =
+                                                    update_document_data(
+                                                        twilio,
+                                                        sync_service,
+                                                        selected_document,
+                                                    )
+                                                    .await
+                                                {
+                                                    *selected_document = updated_document;
+                                                }
                                             }
                                             "Delete" => {
                                                 let confirm_prompt = Confirm::new(
@@ -190,7 +229,7 @@ pub async fn choose_document_action(twilio: &Client, sync_service: &SyncService)
                                                         .delete()
                                                         .await
                                                         .unwrap_or_else(|error| {
-                                                            panic!("{}", error)
+                                                            exit_with_error(&error)
                                                         });
                                                     documents.remove(
                                                             selected_document_index.expect(
@@ -221,3 +260,50 @@ pub async fn choose_document_action(twilio: &Client, sync_service: &SyncService)
         }
     }
 }
+
+/// Prompts the user for new JSON data and updates the Document with it. The
+/// Document's current revision is sent so the update is rejected if the Document
+/// was modified elsewhere since it was fetched.
+///
+/// Returns the updated Document or `None` if the operation was canceled or rejected.
+async fn update_document_data(
+    twilio: &Client,
+    sync_service: &SyncService,
+    document: &SyncDocument,
+) -> Option<SyncDocument> {
+    let initial = document.data.to_string();
+    let data_prompt = Text::new("Provide the new data as JSON:")
+        .with_initial_value(&initial)
+        .with_validator(|val: &str| match serde_json::from_str::<Value>(val) {
+            Ok(_) => Ok(Validation::Valid),
+            Err(_) => Ok(Validation::Invalid("Data must be valid JSON".into())),
+        });
+
+    let data = serde_json::from_str::<Value>(&prompt_user(data_prompt)?)
+        .expect("Unable to parse validated JSON data");
+
+    println!("Updating Document...");
+    match twilio
+        .sync()
+        .service(&sync_service.sid)
+        .document(&document.sid)
+        .update(UpdateParams {
+            if_match: Some(document.revision.clone()),
+            data: &data,
+            ttl: None,
+        })
+        .await
+    {
+        Ok(updated_document) => {
+            println!("Document updated.");
+            println!();
+            Some(updated_document)
+        }
+        Err(error) if error.is_precondition_failed() => {
+            println!("The Document was modified since it was fetched. No changes were made.");
+            println!();
+            None
+        }
+        Err(error) => exit_with_error(&error),
+    }
+}
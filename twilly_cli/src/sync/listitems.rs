@@ -4,10 +4,17 @@ use inquire::{Confirm, Select};
 use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter, EnumString};
 use twilly::{
-    sync::{listitems::ListParams, lists::SyncList, services::SyncService},
+    sync::{
+        listitems::{Bounds, ListParams, Order, SyncListItem},
+        lists::SyncList,
+        services::SyncService,
+    },
     Client,
 };
-use twilly_cli::{get_action_choice_from_user, prompt_user, prompt_user_selection, ActionChoice};
+use twilly_cli::{
+    exit_with_error, get_action_choice_from_user, print_list_progress, print_resource, prompt_user,
+    prompt_user_selection, ActionChoice, DEFAULT_LIST_LIMIT, LOAD_MORE_OPTION,
+};
 
 #[derive(Debug, Clone, Display, EnumIter, EnumString)]
 pub enum Action {
@@ -19,18 +26,8 @@ pub enum Action {
 }
 
 pub async fn choose_list_item_action(twilio: &Client, sync_service: &SyncService, list: &SyncList) {
-    let mut sync_list_items = twilio
-        .sync()
-        .service(&sync_service.sid)
-        .list(&list.sid)
-        .listitems()
-        .list(ListParams {
-            order: None,
-            bounds: None,
-            from: None,
-        })
-        .await
-        .unwrap_or_else(|error| panic!("{}", error));
+    let mut sync_list_items = fetch_list_items(twilio, sync_service, list, None).await;
+    let mut has_more = sync_list_items.len() == DEFAULT_LIST_LIMIT;
 
     if sync_list_items.is_empty() {
         println!("No Sync List items found.");
@@ -47,6 +44,7 @@ pub async fn choose_list_item_action(twilio: &Client, sync_service: &SyncService
             sync_list_items
                 .iter()
                 .map(|list_item| format!("{}", list_item.index))
+                .chain(has_more.then(|| LOAD_MORE_OPTION.to_string()))
                 .collect::<Vec<String>>(),
             "Choose a Sync List item: ",
         ) {
@@ -55,6 +53,15 @@ pub async fn choose_list_item_action(twilio: &Client, sync_service: &SyncService
                     break;
                 }
                 ActionChoice::Exit => process::exit(0),
+                ActionChoice::Other(choice) if has_more && choice == LOAD_MORE_OPTION => {
+                    let last_index = sync_list_items.last().map(|list_item| list_item.index);
+                    let mut more_items =
+                        fetch_list_items(twilio, sync_service, list, last_index).await;
+                    has_more = more_items.len() == DEFAULT_LIST_LIMIT;
+                    sync_list_items.append(&mut more_items);
+                    println!("Found {} Sync List items.", sync_list_items.len());
+                    continue;
+                }
                 ActionChoice::Other(choice) => {
                     let sync_list_position = sync_list_items
                         .iter()
@@ -74,8 +81,7 @@ pub async fn choose_list_item_action(twilio: &Client, sync_service: &SyncService
         if let Some(resource) = prompt_user_selection(resource_selection_prompt) {
             match resource {
                 Action::ListDetails => {
-                    println!("{:#?}", selected_sync_list_item);
-                    println!();
+                    print_resource(&selected_sync_list_item);
                 }
                 Action::Delete => {
                     let confirm_prompt =
@@ -84,15 +90,23 @@ pub async fn choose_list_item_action(twilio: &Client, sync_service: &SyncService
                             .with_default(false);
                     let confirmation = prompt_user(confirm_prompt);
                     if confirmation.is_some() && confirmation.unwrap() {
-                        println!("Deleting Sync Map item...");
-                        twilio
+                        println!("Deleting Sync List item...");
+                        match twilio
                             .sync()
                             .service(&sync_service.sid)
                             .list(&list.sid)
                             .listitem(&selected_sync_list_item.index)
-                            .delete()
+                            .delete(Some(&selected_sync_list_item.revision))
                             .await
-                            .unwrap_or_else(|error| panic!("{}", error));
+                        {
+                            Ok(_) => (),
+                            Err(error) if error.is_precondition_failed() => {
+                                println!("The Sync List item was modified since it was fetched. No changes were made.");
+                                println!();
+                                continue;
+                            }
+                            Err(error) => exit_with_error(&error),
+                        }
                         sync_list_items.remove(selected_sync_list_index.expect(
                             "Could not find Sync List item in existing Sync List items list",
                         ));
@@ -109,3 +123,33 @@ pub async fn choose_list_item_action(twilio: &Client, sync_service: &SyncService
         }
     }
 }
+
+/// Fetches up to `DEFAULT_LIST_LIMIT` items of the List, starting after the item at
+/// `after_index` or from the first item when `None`.
+async fn fetch_list_items(
+    twilio: &Client,
+    sync_service: &SyncService,
+    list: &SyncList,
+    after_index: Option<u32>,
+) -> Vec<SyncListItem> {
+    let sync_list_items = twilio
+        .sync()
+        .service(&sync_service.sid)
+        .list(&list.sid)
+        .listitems()
+        .list_with_progress(
+            ListParams {
+                order: Some(Order::Asc),
+                from: after_index.map(|index| index.to_string()),
+                bounds: after_index.map(|_| Bounds::Exclusive),
+                page_size: Some(DEFAULT_LIST_LIMIT as u16),
+                limit: Some(DEFAULT_LIST_LIMIT),
+            },
+            print_list_progress,
+        )
+        .await
+        .unwrap_or_else(|error| exit_with_error(&error));
+    eprintln!();
+
+    sync_list_items
+}
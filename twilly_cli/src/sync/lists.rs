@@ -1,10 +1,13 @@
 use std::process;
 
-use inquire::{Confirm, Select};
+use inquire::{validator::Validation, Confirm, Select, Text};
 use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter, EnumString};
 use twilly::{sync::services::SyncService, Client};
-use twilly_cli::{get_action_choice_from_user, prompt_user, prompt_user_selection, ActionChoice};
+use twilly_cli::{
+    concurrency, exit_with_error, get_action_choice_from_user, last_modified_suffix,
+    print_resource, prompt_user, prompt_user_selection, sort_by_last_modified, ActionChoice,
+};
 
 use crate::sync::listitems;
 
@@ -14,6 +17,8 @@ pub enum Action {
     ListItem,
     #[strum(to_string = "List Details")]
     ListDetails,
+    #[strum(to_string = "Set TTL on all items")]
+    SetItemsTtl,
     Delete,
     Back,
     Exit,
@@ -26,7 +31,7 @@ pub async fn choose_list_action(twilio: &Client, sync_service: &SyncService) {
         .lists()
         .list()
         .await
-        .unwrap_or_else(|error| panic!("{}", error));
+        .unwrap_or_else(|error| exit_with_error(&error));
 
     if sync_lists.is_empty() {
         println!("No Sync Lists found.");
@@ -34,6 +39,7 @@ pub async fn choose_list_action(twilio: &Client, sync_service: &SyncService) {
     }
 
     println!("Found {} Sync Lists.", sync_lists.len());
+    sort_by_last_modified(&mut sync_lists, |list| &list.date_updated);
 
     let mut selected_sync_list_index: Option<usize> = None;
     loop {
@@ -42,7 +48,14 @@ pub async fn choose_list_action(twilio: &Client, sync_service: &SyncService) {
         } else if let Some(action_choice) = get_action_choice_from_user(
             sync_lists
                 .iter()
-                .map(|list| format!("({}) {}", list.sid, list.unique_name))
+                .map(|list| {
+                    format!(
+                        "({}) {}{}",
+                        list.sid,
+                        list.unique_name,
+                        last_modified_suffix(&list.date_updated)
+                    )
+                })
                 .collect::<Vec<String>>(),
             "Choose a Sync List: ",
         ) {
@@ -75,8 +88,61 @@ pub async fn choose_list_action(twilio: &Client, sync_service: &SyncService) {
                 }
 
                 Action::ListDetails => {
-                    println!("{:#?}", selected_sync_list);
-                    println!();
+                    print_resource(&selected_sync_list);
+                }
+                Action::SetItemsTtl => {
+                    let ttl_prompt = Text::new("How long should each item exist (in seconds)?")
+                        .with_help_message("0 removes any existing TTL.")
+                        .with_validator(|val: &str| match val.parse::<u16>() {
+                            Ok(_) => Ok(Validation::Valid),
+                            Err(_) => Ok(Validation::Invalid(
+                                "TTL must be a number of seconds between 0 and 65535".into(),
+                            )),
+                        });
+
+                    if let Some(ttl) = prompt_user(ttl_prompt) {
+                        let confirm_prompt = Confirm::new(
+                            "Are you sure you wish to set the TTL of every item in the Sync List?",
+                        )
+                        .with_placeholder("N")
+                        .with_default(false);
+                        let confirmation = prompt_user(confirm_prompt);
+                        if confirmation.is_some() && confirmation.unwrap() {
+                            println!("Updating Sync List items...");
+                            let retries_before = twilio.stats().retries;
+                            let results = twilio
+                                .sync()
+                                .service(&sync_service.sid)
+                                .list(&selected_sync_list.sid)
+                                .listitems()
+                                .refresh_ttl(
+                                    ttl.parse::<u16>().expect("Unable to parse validated TTL"),
+                                    concurrency(),
+                                )
+                                .await
+                                .unwrap_or_else(|error| exit_with_error(&error));
+
+                            let failures = results
+                                .iter()
+                                .filter(|(_, result)| result.is_err())
+                                .collect::<Vec<_>>();
+                            println!(
+                                "Updated {} of {} Sync List items.",
+                                results.len() - failures.len(),
+                                results.len()
+                            );
+                            let retries = twilio.stats().retries - retries_before;
+                            if retries > 0 {
+                                println!("{} requests were retried.", retries);
+                            }
+                            for (item, result) in failures {
+                                if let Err(error) = result {
+                                    println!("Failed to update '{}': {}", item, error);
+                                }
+                            }
+                            println!();
+                        }
+                    }
                 }
                 Action::Delete => {
                     let confirm_prompt =
@@ -92,7 +158,7 @@ pub async fn choose_list_action(twilio: &Client, sync_service: &SyncService) {
                             .list(&selected_sync_list.sid)
                             .delete()
                             .await
-                            .unwrap_or_else(|error| panic!("{}", error));
+                            .unwrap_or_else(|error| exit_with_error(&error));
                         sync_lists.remove(
                             selected_sync_list_index
                                 .expect("Could not find Sync List in existing Sync Maps list"),
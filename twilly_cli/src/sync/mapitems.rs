@@ -1,36 +1,35 @@
 use std::process;
 
-use inquire::{Confirm, Select};
+use inquire::{validator::Validation, Confirm, Select, Text};
 use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter, EnumString};
 use twilly::{
-    sync::{mapitems::ListParams, maps::SyncMap, services::SyncService},
+    sync::{
+        mapitems::{Bounds, ListParams, Order, SyncMapItem},
+        maps::SyncMap,
+        services::SyncService,
+    },
     Client,
 };
-use twilly_cli::{get_action_choice_from_user, prompt_user, prompt_user_selection, ActionChoice};
+use twilly_cli::{
+    exit_with_error, get_action_choice_from_user, print_list_progress, print_resource, prompt_user,
+    prompt_user_selection, ActionChoice, DEFAULT_LIST_LIMIT, LOAD_MORE_OPTION,
+};
 
 #[derive(Debug, Clone, Display, EnumIter, EnumString)]
 pub enum Action {
     #[strum(to_string = "List Details")]
     ListDetails,
+    #[strum(to_string = "Rename key")]
+    Rename,
     Delete,
     Back,
     Exit,
 }
 
 pub async fn choose_map_item_action(twilio: &Client, sync_service: &SyncService, map: &SyncMap) {
-    let mut sync_map_items = twilio
-        .sync()
-        .service(&sync_service.sid)
-        .map(&map.sid)
-        .mapitems()
-        .list(ListParams {
-            order: None,
-            bounds: None,
-            from: None,
-        })
-        .await
-        .unwrap_or_else(|error| panic!("{}", error));
+    let mut sync_map_items = fetch_map_items(twilio, sync_service, map, None).await;
+    let mut has_more = sync_map_items.len() == DEFAULT_LIST_LIMIT;
 
     if sync_map_items.is_empty() {
         println!("No Sync Map items found.");
@@ -47,6 +46,7 @@ pub async fn choose_map_item_action(twilio: &Client, sync_service: &SyncService,
             sync_map_items
                 .iter()
                 .map(|map_item| map_item.key.to_string())
+                .chain(has_more.then(|| LOAD_MORE_OPTION.to_string()))
                 .collect::<Vec<String>>(),
             "Choose a Sync Map item: ",
         ) {
@@ -55,6 +55,14 @@ pub async fn choose_map_item_action(twilio: &Client, sync_service: &SyncService,
                     break;
                 }
                 ActionChoice::Exit => process::exit(0),
+                ActionChoice::Other(choice) if has_more && choice == LOAD_MORE_OPTION => {
+                    let last_key = sync_map_items.last().map(|map_item| map_item.key.clone());
+                    let mut more_items = fetch_map_items(twilio, sync_service, map, last_key).await;
+                    has_more = more_items.len() == DEFAULT_LIST_LIMIT;
+                    sync_map_items.append(&mut more_items);
+                    println!("Found {} Sync Maps items.", sync_map_items.len());
+                    continue;
+                }
                 ActionChoice::Other(choice) => {
                     let sync_map_position = sync_map_items
                         .iter()
@@ -74,8 +82,33 @@ pub async fn choose_map_item_action(twilio: &Client, sync_service: &SyncService,
         if let Some(resource) = prompt_user_selection(resource_selection_prompt) {
             match resource {
                 Action::ListDetails => {
-                    println!("{:#?}", selected_sync_map_item);
-                    println!();
+                    print_resource(&selected_sync_map_item);
+                }
+                Action::Rename => {
+                    let key_prompt =
+                        Text::new("Provide the new key:").with_validator(|val: &str| {
+                            match !val.trim().is_empty() {
+                                true => Ok(Validation::Valid),
+                                false => {
+                                    Ok(Validation::Invalid("Enter at least one character".into()))
+                                }
+                            }
+                        });
+
+                    if let Some(new_key) = prompt_user(key_prompt) {
+                        println!("Renaming Sync Map item...");
+                        let renamed_map_item = twilio
+                            .sync()
+                            .service(&sync_service.sid)
+                            .map(&map.sid)
+                            .mapitem(&selected_sync_map_item.key)
+                            .rename(new_key.trim())
+                            .await
+                            .unwrap_or_else(|error| exit_with_error(&error));
+                        *selected_sync_map_item = renamed_map_item;
+                        println!("Sync Map item renamed.");
+                        println!();
+                    }
                 }
                 Action::Delete => {
                     let confirm_prompt =
@@ -85,14 +118,22 @@ pub async fn choose_map_item_action(twilio: &Client, sync_service: &SyncService,
                     let confirmation = prompt_user(confirm_prompt);
                     if confirmation.is_some() && confirmation.unwrap() {
                         println!("Deleting Sync Map item...");
-                        twilio
+                        match twilio
                             .sync()
                             .service(&sync_service.sid)
                             .map(&map.sid)
                             .mapitem(&selected_sync_map_item.key)
-                            .delete()
+                            .delete(Some(&selected_sync_map_item.revision))
                             .await
-                            .unwrap_or_else(|error| panic!("{}", error));
+                        {
+                            Ok(_) => (),
+                            Err(error) if error.is_precondition_failed() => {
+                                println!("The Sync Map item was modified since it was fetched. No changes were made.");
+                                println!();
+                                continue;
+                            }
+                            Err(error) => exit_with_error(&error),
+                        }
                         sync_map_items.remove(selected_sync_map_index.expect(
                             "Could not find Sync Map item in existing Sync Map items list",
                         ));
@@ -109,3 +150,33 @@ pub async fn choose_map_item_action(twilio: &Client, sync_service: &SyncService,
         }
     }
 }
+
+/// Fetches up to `DEFAULT_LIST_LIMIT` items of the Map, starting after the item keyed
+/// `after_key` or from the first item when `None`.
+async fn fetch_map_items(
+    twilio: &Client,
+    sync_service: &SyncService,
+    map: &SyncMap,
+    after_key: Option<String>,
+) -> Vec<SyncMapItem> {
+    let sync_map_items = twilio
+        .sync()
+        .service(&sync_service.sid)
+        .map(&map.sid)
+        .mapitems()
+        .list_with_progress(
+            ListParams {
+                order: Some(Order::Asc),
+                bounds: after_key.as_ref().map(|_| Bounds::Exclusive),
+                from: after_key,
+                page_size: Some(DEFAULT_LIST_LIMIT as u16),
+                limit: Some(DEFAULT_LIST_LIMIT),
+            },
+            print_list_progress,
+        )
+        .await
+        .unwrap_or_else(|error| exit_with_error(&error));
+    eprintln!();
+
+    sync_map_items
+}
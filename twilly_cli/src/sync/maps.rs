@@ -2,17 +2,18 @@ use std::process;
 
 use inquire::{validator::Validation, Confirm, Select, Text};
 use regex::Regex;
+use serde_json::Value;
 use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter, EnumString};
 use twilly::{
-    sync::{
-        mapitems::{CreateParams as CreateMapItemParams, ListParams},
-        maps::CreateParams as CreateMapParams,
-        services::SyncService,
-    },
-    Client,
+    sync::{mapitems::ListParams, maps::CreateParams as CreateMapParams, services::SyncService},
+    Client, MAX_PAGE_SIZE,
+};
+use twilly_cli::{
+    concurrency, exit_with_error, get_action_choice_from_user, last_modified_suffix,
+    print_bulk_summary, print_resource, prompt_user, prompt_user_selection, sort_by_last_modified,
+    symbol, ActionChoice,
 };
-use twilly_cli::{get_action_choice_from_user, prompt_user, prompt_user_selection, ActionChoice};
 
 use crate::sync::mapitems;
 
@@ -22,6 +23,10 @@ pub enum Action {
     MapItem,
     #[strum(to_string = "List Details")]
     ListDetails,
+    #[strum(to_string = "Set TTL on all items")]
+    SetItemsTtl,
+    #[strum(to_string = "Import items from JSON file")]
+    ImportItems,
     Rename,
     Delete,
     Back,
@@ -35,7 +40,7 @@ pub async fn choose_map_action(twilio: &Client, sync_service: &SyncService) {
         .maps()
         .list()
         .await
-        .unwrap_or_else(|error| panic!("{}", error));
+        .unwrap_or_else(|error| exit_with_error(&error));
 
     if sync_maps.is_empty() {
         println!("No Sync Maps found.");
@@ -43,6 +48,7 @@ pub async fn choose_map_action(twilio: &Client, sync_service: &SyncService) {
     }
 
     println!("Found {} Sync Maps.", sync_maps.len());
+    sort_by_last_modified(&mut sync_maps, |map| &map.date_updated);
 
     let mut selected_sync_map_index: Option<usize> = None;
     loop {
@@ -51,7 +57,14 @@ pub async fn choose_map_action(twilio: &Client, sync_service: &SyncService) {
         } else if let Some(action_choice) = get_action_choice_from_user(
             sync_maps
                 .iter()
-                .map(|map| format!("({}) {}", map.sid, map.unique_name))
+                .map(|map| {
+                    format!(
+                        "({}) {}{}",
+                        map.sid,
+                        map.unique_name,
+                        last_modified_suffix(&map.date_updated)
+                    )
+                })
                 .collect::<Vec<String>>(),
             "Choose a Sync Map: ",
         ) {
@@ -83,8 +96,7 @@ pub async fn choose_map_action(twilio: &Client, sync_service: &SyncService) {
                 }
 
                 Action::ListDetails => {
-                    println!("{:#?}", selected_sync_map);
-                    println!();
+                    print_resource(&selected_sync_map);
                 }
                 Action::Rename => {
                     let get_name_prompt = Text::new(
@@ -108,7 +120,8 @@ pub async fn choose_map_action(twilio: &Client, sync_service: &SyncService) {
 
                     println!("Name confirmed '{trimmed_name}'");
 
-                    let confirmation_message = "⚠️ Warning ⚠️
+                    let confirmation_message = format!(
+                        "{warning} Warning {warning}
 
 This process is non-reversible. We will:
     1. Create a temporary map to hold a copy of the map items
@@ -118,13 +131,16 @@ This process is non-reversible. We will:
     5. Create a new map with your new name
     6. Copy all items from the temporary map into the new map
 
-💡 Please note the TTL will not be preserved for the Map or items.
+{tip} Please note the TTL will not be preserved for the Map or items.
 
 We will not delete the temporary map after the process has completed.
 You can remove this using the CLI after you've confirmed the rename was successful.
 
-Would you like to continue?";
-                    let confirm_operation = Confirm::new(confirmation_message)
+Would you like to continue?",
+                        warning = symbol("⚠️", "!"),
+                        tip = symbol("💡", "*"),
+                    );
+                    let confirm_operation = Confirm::new(&confirmation_message)
                         .with_placeholder("N")
                         .with_default(false);
 
@@ -165,9 +181,8 @@ Would you like to continue?";
                         .map(&selected_sync_map.sid)
                         .mapitems()
                         .list(ListParams {
-                            bounds: None,
-                            from: None,
-                            order: None,
+                            page_size: Some(MAX_PAGE_SIZE),
+                            ..Default::default()
                         })
                         .await;
 
@@ -178,24 +193,23 @@ Would you like to continue?";
 
                     let items = fetch_items_result.unwrap();
 
-                    for item in items.iter() {
-                        let create_item_result = twilio
-                            .sync()
-                            .service(&sync_service.sid)
-                            .map(&temp_map.sid)
-                            .mapitems()
-                            .create(CreateMapItemParams {
-                                key: String::from(&item.key),
-                                data: &item.data,
-                                collection_ttl: None,
-                                ttl: None,
-                            })
-                            .await;
+                    let copy_results = twilio
+                        .sync()
+                        .service(&sync_service.sid)
+                        .map(&temp_map.sid)
+                        .mapitems()
+                        .import(
+                            items
+                                .iter()
+                                .map(|item| (item.key.clone(), item.data.clone())),
+                            concurrency(),
+                        )
+                        .await;
 
-                        if let Err(error) = create_item_result {
-                            println!("Errored: Failed while taking copy of items: {:?}", error);
-                            return;
-                        }
+                    if copy_results.iter().any(|(_, result)| result.is_err()) {
+                        print_bulk_summary(&copy_results, "Copied", "Sync Map items");
+                        println!("Errored: Failed while taking copy of items");
+                        return;
                     }
 
                     // confirm copy
@@ -225,7 +239,7 @@ Would you like to continue?";
                         .map(&selected_sync_map.sid)
                         .delete()
                         .await
-                        .unwrap_or_else(|error| panic!("{}", error));
+                        .unwrap_or_else(|error| exit_with_error(&error));
                     sync_maps.remove(
                         selected_sync_map_index
                             .expect("Could not find Sync Map in existing Sync Maps list"),
@@ -252,31 +266,108 @@ Would you like to continue?";
 
                     // clone all items into new map
                     println!("(6/6) Clone items into new map");
-                    for item in items.iter() {
-                        let create_item_result = twilio
-                            .sync()
-                            .service(&sync_service.sid)
-                            .map(&new_map.sid)
-                            .mapitems()
-                            .create(CreateMapItemParams {
-                                key: String::from(&item.key),
-                                data: &item.data,
-                                collection_ttl: None,
-                                ttl: None,
-                            })
-                            .await;
+                    let copy_results = twilio
+                        .sync()
+                        .service(&sync_service.sid)
+                        .map(&new_map.sid)
+                        .mapitems()
+                        .import(
+                            items
+                                .iter()
+                                .map(|item| (item.key.clone(), item.data.clone())),
+                            concurrency(),
+                        )
+                        .await;
 
-                        if let Err(error) = create_item_result {
+                    if copy_results.iter().any(|(_, result)| result.is_err()) {
+                        print_bulk_summary(&copy_results, "Copied", "Sync Map items");
+                        println!("Errored: Failed while copying items to new map");
+                        return;
+                    }
+
+                    println!("Map rename complete");
+                    break;
+                }
+                Action::SetItemsTtl => {
+                    let ttl_prompt = Text::new("How long should each item exist (in seconds)?")
+                        .with_help_message("0 removes any existing TTL.")
+                        .with_validator(|val: &str| match val.parse::<u16>() {
+                            Ok(_) => Ok(Validation::Valid),
+                            Err(_) => Ok(Validation::Invalid(
+                                "TTL must be a number of seconds between 0 and 65535".into(),
+                            )),
+                        });
+
+                    if let Some(ttl) = prompt_user(ttl_prompt) {
+                        let confirm_prompt = Confirm::new(
+                            "Are you sure you wish to set the TTL of every item in the Sync Map?",
+                        )
+                        .with_placeholder("N")
+                        .with_default(false);
+                        let confirmation = prompt_user(confirm_prompt);
+                        if confirmation.is_some() && confirmation.unwrap() {
+                            println!("Updating Sync Map items...");
+                            let retries_before = twilio.stats().retries;
+                            let results = twilio
+                                .sync()
+                                .service(&sync_service.sid)
+                                .map(&selected_sync_map.sid)
+                                .mapitems()
+                                .refresh_ttl(
+                                    ttl.parse::<u16>().expect("Unable to parse validated TTL"),
+                                    concurrency(),
+                                )
+                                .await
+                                .unwrap_or_else(|error| exit_with_error(&error));
+
+                            let failures = results
+                                .iter()
+                                .filter(|(_, result)| result.is_err())
+                                .collect::<Vec<_>>();
                             println!(
-                                "Errored: Failed while copying items to new map: {:?}",
-                                error
+                                "Updated {} of {} Sync Map items.",
+                                results.len() - failures.len(),
+                                results.len()
                             );
-                            return;
+                            let retries = twilio.stats().retries - retries_before;
+                            if retries > 0 {
+                                println!("{} requests were retried.", retries);
+                            }
+                            for (item, result) in failures {
+                                if let Err(error) = result {
+                                    println!("Failed to update '{}': {}", item, error);
+                                }
+                            }
+                            println!();
                         }
                     }
+                }
+                Action::ImportItems => {
+                    let path_prompt = Text::new("Path of the JSON file to import:")
+                        .with_help_message(
+                        "A JSON object whose keys and values become the Map Item keys and data.",
+                    );
 
-                    println!("Map rename complete");
-                    break;
+                    if let Some(path) = prompt_user(path_prompt) {
+                        let items = match read_items_file(path.trim()) {
+                            Ok(items) => items,
+                            Err(error) => {
+                                println!("{}", error);
+                                println!();
+                                continue;
+                            }
+                        };
+
+                        println!("Importing {} Sync Map items...", items.len());
+                        let results = twilio
+                            .sync()
+                            .service(&sync_service.sid)
+                            .map(&selected_sync_map.sid)
+                            .mapitems()
+                            .import(items, concurrency())
+                            .await;
+                        print_bulk_summary(&results, "Imported", "Sync Map items");
+                    }
                 }
                 Action::Delete => {
                     let confirm_prompt =
@@ -292,7 +383,7 @@ Would you like to continue?";
                             .map(&selected_sync_map.sid)
                             .delete()
                             .await
-                            .unwrap_or_else(|error| panic!("{}", error));
+                            .unwrap_or_else(|error| exit_with_error(&error));
                         sync_maps.remove(
                             selected_sync_map_index
                                 .expect("Could not find Sync Map in existing Sync Maps list"),
@@ -310,3 +401,19 @@ Would you like to continue?";
         }
     }
 }
+
+/// Reads the Map Items to import from the JSON object in the file at `path`, keyed by
+/// Map Item key.
+fn read_items_file(path: &str) -> Result<serde_json::Map<String, Value>, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|error| format!("Unable to read '{}': {}", path, error))?;
+
+    match serde_json::from_str::<Value>(&contents) {
+        Ok(Value::Object(items)) => Ok(items),
+        Ok(_) => Err(format!(
+            "'{}' must contain a JSON object of Map Item keys to data",
+            path
+        )),
+        Err(error) => Err(format!("'{}' is not valid JSON: {}", path, error)),
+    }
+}